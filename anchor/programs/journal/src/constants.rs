@@ -0,0 +1,237 @@
+//! Limits and seed prefixes shared with clients.
+//!
+//! Every value here is annotated with `#[constant]` so it is exported in the IDL,
+//! letting the web app and other consumers read it instead of keeping their own copies.
+//! Seed prefixes are byte arrays used as `SEED.as_ref()`, the only constant form that both
+//! the `idl-build` feature and the IDL parser's PDA seed resolution accept: `idl-build` rejects
+//! `&str` constants, and the seed resolution cannot read the type of `&[u8]` ones. The parser
+//! splits byte-string constants on the letter `b`, so seed strings must not contain it.
+use anchor_lang::prelude::*;
+
+/// The largest maximum title length the configuration can set, in bytes.
+#[constant]
+pub const MAX_TITLE_LENGTH: u32 = 50;
+
+/// The largest maximum message length the configuration can set, in bytes.
+#[constant]
+pub const MAX_MESSAGE_LENGTH: u32 = 1000;
+
+/// The bytes encryption adds to a message: the authentication tag.
+#[constant]
+pub const CIPHERTEXT_OVERHEAD: u32 = 16;
+
+/// The largest ciphertext an encrypted entry can hold, in bytes.
+#[constant]
+pub const MAX_CIPHERTEXT_LENGTH: u32 = MAX_MESSAGE_LENGTH + CIPHERTEXT_OVERHEAD;
+
+/// The maximum length of a reader's wrapped decryption key, in bytes.
+#[constant]
+pub const MAX_WRAPPED_KEY_LENGTH: u32 = 128;
+
+/// The maximum number of owners of a multisig journal.
+#[constant]
+pub const MAX_MULTISIG_OWNERS: u32 = 5;
+
+/// The maximum length of a journal name, in bytes. Names are used directly as seeds.
+#[constant]
+pub const MAX_JOURNAL_NAME_LENGTH: u32 = 32;
+
+/// The maximum length of a journal description, in bytes.
+#[constant]
+pub const MAX_JOURNAL_DESCRIPTION_LENGTH: u32 = 200;
+
+/// The number of entries listed on each entry index page.
+#[constant]
+pub const ENTRY_INDEX_PAGE_CAPACITY: u32 = 32;
+
+/// The maximum number of tags on an entry.
+#[constant]
+pub const MAX_TAGS: u32 = 5;
+
+/// The maximum length of a tag, in bytes.
+#[constant]
+pub const MAX_TAG_LENGTH: u32 = 20;
+
+/// The maximum number of attachments on an entry.
+#[constant]
+pub const MAX_ATTACHMENTS: u32 = 4;
+
+/// The maximum length of an attachment content identifier, in bytes.
+#[constant]
+pub const MAX_ATTACHMENT_LENGTH: u32 = 64;
+
+/// The byte offset of an entry's category in its account data: the discriminator and owner.
+#[constant]
+pub const ENTRY_CATEGORY_OFFSET: u32 = 8 + 32;
+
+/// The maximum nesting depth of objects and arrays in a JSON entry.
+#[constant]
+pub const MAX_JSON_DEPTH: u32 = 16;
+
+/// The maximum length of a notarization label, in bytes.
+#[constant]
+pub const MAX_LABEL_LENGTH: u32 = 32;
+
+/// The maximum length of a table of contents section header, in bytes.
+#[constant]
+pub const MAX_SECTION_HEADER_LENGTH: u32 = 32;
+
+/// The maximum number of items in a table of contents.
+#[constant]
+pub const MAX_TOC_ITEMS: u32 = 64;
+
+/// The maximum number of programs on the CPI allowlist.
+#[constant]
+pub const MAX_CPI_ALLOWLIST: u32 = 8;
+
+/// The number of records retained in an entry's audit log.
+#[constant]
+pub const AUDIT_LOG_CAPACITY: u32 = 16;
+
+/// The maximum length of a prompt pack name, in bytes.
+#[constant]
+pub const MAX_PACK_NAME_LENGTH: u32 = 32;
+
+/// The maximum number of prompts in a prompt pack.
+#[constant]
+pub const MAX_PROMPTS_PER_PACK: u32 = 10;
+
+/// The maximum length of a single prompt, in bytes.
+#[constant]
+pub const MAX_PROMPT_LENGTH: u32 = 200;
+
+/// The maximum number of revisions retained per entry.
+#[constant]
+pub const MAX_REVISION_DEPTH: u32 = 8;
+
+/// The minimum length of a slug, in bytes.
+#[constant]
+pub const MIN_SLUG_LENGTH: u32 = 3;
+
+/// The maximum length of a slug, in bytes.
+#[constant]
+pub const MAX_SLUG_LENGTH: u32 = 32;
+
+/// The maximum treasury fee, in basis points (100%).
+#[constant]
+pub const MAX_FEE_BPS: u16 = 10_000;
+
+/// The default time a deleted entry stays in the trash before it can be purged, in seconds.
+#[constant]
+pub const DEFAULT_TRASH_GRACE_PERIOD: i64 = 7 * 24 * 60 * 60;
+
+/// How long a multisig proposal can gather approvals and be executed, in seconds.
+#[constant]
+pub const MULTISIG_PROPOSAL_LIFETIME: i64 = 7 * 24 * 60 * 60;
+
+/// The seed prefix of the program configuration account.
+#[constant]
+pub const CONFIG_SEED: [u8; 6] = *b"config";
+
+/// The seed prefix of the global statistics shards.
+#[constant]
+pub const GLOBAL_STATS_SEED: [u8; 5] = *b"stats";
+
+/// The number of shards the global statistics are split into.
+#[constant]
+pub const GLOBAL_STATS_SHARDS: u32 = 16;
+
+/// The seed prefix of user profile accounts.
+#[constant]
+pub const PROFILE_SEED: [u8; 7] = *b"profile";
+
+/// The seed prefix of journal entry accounts.
+#[constant]
+pub const ENTRY_SEED: [u8; 5] = *b"entry";
+
+/// The seed prefix of entry index page accounts.
+#[constant]
+pub const ENTRY_INDEX_SEED: [u8; 11] = *b"entry_index";
+
+/// The seed prefix of permit receipt accounts.
+#[constant]
+pub const PERMIT_SEED: [u8; 6] = *b"permit";
+
+/// The seed prefix of audit log accounts.
+#[constant]
+pub const AUDIT_LOG_SEED: [u8; 5] = *b"audit";
+
+/// The seed prefix of table of contents accounts.
+#[constant]
+pub const TOC_SEED: [u8; 3] = *b"toc";
+
+/// The seed prefix of view record accounts.
+#[constant]
+pub const VIEW_SEED: [u8; 4] = *b"view";
+
+/// The seed prefix of document notarization accounts.
+#[constant]
+pub const NOTARIZATION_SEED: [u8; 12] = *b"notarization";
+
+/// The seed prefix of pending entry accounts.
+#[constant]
+pub const PENDING_ENTRY_SEED: [u8; 7] = *b"pending";
+
+/// The seed prefix of prompt pack accounts.
+#[constant]
+pub const PROMPT_PACK_SEED: [u8; 11] = *b"prompt_pack";
+
+/// The seed prefix of prompt pack access accounts.
+#[constant]
+pub const PROMPT_ACCESS_SEED: [u8; 13] = *b"prompt_access";
+
+/// The seed prefix of slug accounts.
+#[constant]
+pub const SLUG_SEED: [u8; 4] = *b"slug";
+
+/// The seed prefix of revision history accounts.
+#[constant]
+pub const REVISION_SEED: [u8; 8] = *b"revision";
+
+/// The seed prefix of entry snapshot accounts.
+#[constant]
+pub const SNAPSHOT_SEED: [u8; 8] = *b"snapshot";
+
+/// The seed prefix of write delegate accounts.
+#[constant]
+pub const DELEGATE_SEED: [u8; 8] = *b"delegate";
+
+/// The seed prefix of mood statistics accounts.
+#[constant]
+pub const MOOD_STATS_SEED: [u8; 4] = *b"mood";
+
+/// The seed prefix of encryption key registry accounts.
+#[constant]
+pub const KEY_REGISTRY_SEED: [u8; 12] = *b"key_registry";
+
+/// The seed prefix of access grant accounts.
+#[constant]
+pub const ACCESS_GRANT_SEED: [u8; 6] = *b"access";
+
+/// The seed prefix of multisig journal accounts.
+#[constant]
+pub const MULTISIG_SEED: [u8; 8] = *b"multisig";
+
+/// The seed prefix of multisig proposal accounts.
+#[constant]
+pub const MULTISIG_PROPOSAL_SEED: [u8; 8] = *b"proposal";
+
+/// The seed prefix of shared journal accounts.
+#[constant]
+pub const SHARED_JOURNAL_SEED: [u8; 6] = *b"shared";
+
+/// The seed prefix of shared journal membership accounts, which hold a member's role.
+#[constant]
+pub const ROLE_SEED: [u8; 4] = *b"role";
+
+/// The seed prefix of shared journal invitation accounts.
+#[constant]
+pub const INVITATION_SEED: [u8; 6] = *b"invite";
+
+/// The seed prefix of named journal accounts.
+#[constant]
+pub const JOURNAL_SEED: [u8; 7] = *b"journal";
+
+/// The seed prefix of attestation accounts.
+#[constant]
+pub const ATTESTATION_SEED: [u8; 11] = *b"attestation";
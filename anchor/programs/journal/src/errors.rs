@@ -0,0 +1,194 @@
+use anchor_lang::prelude::*;
+
+/// Errors returned by the journal program.
+#[error_code]
+pub enum JournalError {
+    #[msg("The signer is not the owner of the entry")]
+    NotOwner,
+    #[msg("The title must not be empty")]
+    TitleEmpty,
+    #[msg("The title exceeds the maximum length")]
+    TitleTooLong,
+    #[msg("The message exceeds the maximum length")]
+    MessageTooLong,
+    #[msg("Entry limits must be between one and the program's maximum lengths")]
+    InvalidEntryLimits,
+    #[msg("The entry is sealed and can no longer be changed")]
+    SealedEntry,
+    #[msg("The entry's edit window has closed")]
+    EditWindowClosed,
+    #[msg("An edit window can only be shortened, not removed or extended")]
+    EditWindowLoosened,
+    #[msg("The entry was modified since it was read")]
+    StaleVersion,
+    #[msg("The entry has not been published")]
+    EntryNotPublished,
+    #[msg("The entry has already been published")]
+    EntryAlreadyPublished,
+    #[msg("The publish time must be in the future")]
+    InvalidPublishTime,
+    #[msg("The entry's publish time has not been reached")]
+    TooEarly,
+    #[msg("The entry is locked until its unlock time")]
+    EntryLocked,
+    #[msg("A lock can only be extended, not shortened")]
+    LockShortened,
+    #[msg("The release delay must be positive")]
+    InvalidReleaseDelay,
+    #[msg("The owner has not configured a release delay")]
+    ReleaseNotConfigured,
+    #[msg("The owner has recorded a heartbeat within the release delay")]
+    OwnerStillActive,
+    #[msg("The signer is not the owner's beneficiary")]
+    NotBeneficiary,
+    #[msg("The signer has not been offered the entry")]
+    NotPendingOwner,
+    #[msg("The write delegation has expired")]
+    DelegateExpired,
+    #[msg("The write delegation does not cover this instruction")]
+    DelegateOutOfScope,
+    #[msg("The entry has too many tags")]
+    TooManyTags,
+    #[msg("Tags must be non-empty and within the maximum length")]
+    InvalidTag,
+    #[msg("The location must give both coordinates within range, or neither")]
+    InvalidLocation,
+    #[msg("The temperature is out of range")]
+    InvalidWeather,
+    #[msg("The message is not well-formed JSON")]
+    InvalidJson,
+    #[msg("The language must be a lowercase ISO 639-1 code")]
+    InvalidLanguage,
+    #[msg("The entry already has the maximum number of attachments")]
+    TooManyAttachments,
+    #[msg("The attachment is not a valid IPFS or Arweave content identifier")]
+    InvalidAttachment,
+    #[msg("The entry has no such attachment")]
+    AttachmentNotFound,
+    #[msg("The entry has no content commitment to reveal")]
+    EntryNotCommitted,
+    #[msg("The revealed content does not match the commitment")]
+    CommitmentMismatch,
+    #[msg("The private entry must be revealed first")]
+    EntryNotRevealed,
+    #[msg("The entry is encrypted")]
+    EntryEncrypted,
+    #[msg("The message was not encrypted under the current key")]
+    StaleEncryptionKey,
+    #[msg("The entry is not encrypted")]
+    EntryNotEncrypted,
+    #[msg("The wrapped key is too long")]
+    WrappedKeyTooLong,
+    #[msg("Owners must be distinct and number between one and the maximum")]
+    InvalidMultisigOwners,
+    #[msg("The threshold must be between one and the number of owners")]
+    InvalidThreshold,
+    #[msg("The signer is not an owner of the multisig journal")]
+    NotMultisigOwner,
+    #[msg("Not enough owners have approved the proposal")]
+    ThresholdNotMet,
+    #[msg("The proposal is for a different action or entry")]
+    ProposalMismatch,
+    #[msg("The member's role does not allow this action")]
+    InsufficientRole,
+    #[msg("The entry does not belong to the shared journal")]
+    NotInSharedJournal,
+    #[msg("Members cannot change their own role")]
+    CannotChangeOwnRole,
+    #[msg("The signer is neither the inviter nor the invitee")]
+    NotInvitationParty,
+    #[msg("The entry was not written by the member")]
+    NotMemberEntry,
+    #[msg("Journal names must be between 1 and 32 bytes")]
+    InvalidJournalName,
+    #[msg("The journal description is too long")]
+    JournalDescriptionTooLong,
+    #[msg("The entry's journal account is required")]
+    JournalRequired,
+    #[msg("The journal account is not the entry's journal")]
+    JournalMismatch,
+    #[msg("The entry is already in that journal")]
+    AlreadyInJournal,
+    #[msg("A neighboring entry in the author's entry list is missing or does not match")]
+    EntryListMismatch,
+    #[msg("The entry is listed on an entry index page that was not passed")]
+    EntryIndexPageRequired,
+    #[msg("The entries passed do not match the entries in the table of contents")]
+    TocEntriesMismatch,
+    #[msg("Answering a prompt requires its prompt pack")]
+    PromptPackRequired,
+    #[msg("The prompt pack has no prompt at this index")]
+    InvalidPromptIndex,
+    #[msg("The prompt pack has not been purchased by the entry owner")]
+    PromptPackNotPurchased,
+    #[msg("Slugs can only point at a profile, journal or table of contents")]
+    InvalidSlugTarget,
+    #[msg("The multisig proposal has expired")]
+    ProposalExpired,
+    #[msg("Only the proposer can cancel a multisig proposal before it expires")]
+    ProposalNotExpired,
+    #[msg("The account is not the entry's or is not followed by the wallet that paid for it")]
+    CompanionAccountMismatch,
+    #[msg("Every view record of the entry must be closed before it is purged")]
+    ViewRecordsOpen,
+    #[msg("The unlock time must be in the future")]
+    InvalidUnlockTime,
+    #[msg("The entry is in the trash")]
+    EntryTrashed,
+    #[msg("The entry is not in the trash")]
+    EntryNotTrashed,
+    #[msg("The entry cannot be purged until its grace period has elapsed")]
+    GracePeriodActive,
+    #[msg("The entry's grace period has elapsed and it can no longer be restored")]
+    GracePeriodElapsed,
+    #[msg("The grace period must not be negative")]
+    InvalidGracePeriod,
+    #[msg("The entry's current title does not match the expected title")]
+    TitleMismatch,
+    #[msg("The signer is not the attester")]
+    NotAttester,
+    #[msg("The label exceeds the maximum length")]
+    LabelTooLong,
+    #[msg("The entry is protected and requires its guardian's signature")]
+    GuardianSignatureRequired,
+    #[msg("The signer is not the entry's witness")]
+    NotWitness,
+    #[msg("The content hash does not match the entry")]
+    ContentHashMismatch,
+    #[msg("The table of contents has too many items")]
+    TooManyTocItems,
+    #[msg("The section header exceeds the maximum length")]
+    SectionHeaderTooLong,
+    #[msg("The signer is not the program's upgrade authority")]
+    NotUpgradeAuthority,
+    #[msg("The CPI allowlist has too many programs")]
+    CpiAllowlistTooLong,
+    #[msg("Invocation via CPI is not allowed for the calling program")]
+    CpiNotAllowed,
+    #[msg("The permit has expired")]
+    PermitExpired,
+    #[msg("The permit is not backed by a matching Ed25519 signature")]
+    InvalidPermitSignature,
+    #[msg("The entry is audited and its audit log must be provided")]
+    AuditLogRequired,
+    #[msg("The fee exceeds 10000 basis points")]
+    InvalidFeeBps,
+    #[msg("The prompt pack name exceeds the maximum length")]
+    PackNameTooLong,
+    #[msg("The prompt pack must contain between one and the maximum number of prompts")]
+    InvalidPromptCount,
+    #[msg("A prompt exceeds the maximum length")]
+    PromptTooLong,
+    #[msg("The treasury account does not match the program configuration")]
+    WrongTreasury,
+    #[msg("Slugs must be 3 to 32 characters of lowercase letters, digits and inner hyphens")]
+    InvalidSlug,
+    #[msg("The revision depth must be between 1 and 8")]
+    InvalidRevisionDepth,
+    #[msg("The entry keeps revisions and its revision history must be provided")]
+    RevisionHistoryRequired,
+    #[msg("The entry has no revisions to restore")]
+    NoRevisions,
+    #[msg("The revision does not match the entry's current content")]
+    RevisionMismatch,
+}
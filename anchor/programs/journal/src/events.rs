@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+
+/// Emitted when a journal entry is created.
+#[event]
+pub struct EntryCreated {
+    /// The owner of the entry.
+    pub owner: Pubkey,
+    /// The journal entry account.
+    pub entry: Pubkey,
+    /// The title of the entry.
+    pub title: String,
+    /// The Unix timestamp of the creation.
+    pub timestamp: i64,
+}
+
+/// Emitted when a journal entry's content is changed.
+#[event]
+pub struct EntryUpdated {
+    /// The owner of the entry.
+    pub owner: Pubkey,
+    /// The journal entry account.
+    pub entry: Pubkey,
+    /// The title of the entry.
+    pub title: String,
+    /// The Unix timestamp of the update.
+    pub timestamp: i64,
+}
+
+/// Emitted when a journal entry changes owner.
+#[event]
+pub struct EntryTransferred {
+    /// The previous owner of the entry.
+    pub from: Pubkey,
+    /// The new owner of the entry.
+    pub to: Pubkey,
+    /// The journal entry account.
+    pub entry: Pubkey,
+    /// The Unix timestamp of the transfer.
+    pub timestamp: i64,
+}
+
+/// Emitted when a journal entry is moved to the trash.
+#[event]
+pub struct EntryTrashed {
+    /// The owner of the entry.
+    pub owner: Pubkey,
+    /// The journal entry account.
+    pub entry: Pubkey,
+    /// The title of the entry.
+    pub title: String,
+    /// The Unix timestamp of the deletion.
+    pub timestamp: i64,
+}
+
+/// Emitted when a journal entry is restored from the trash.
+#[event]
+pub struct EntryRestored {
+    /// The owner of the entry.
+    pub owner: Pubkey,
+    /// The journal entry account.
+    pub entry: Pubkey,
+    /// The title of the entry.
+    pub title: String,
+    /// The Unix timestamp of the restoration.
+    pub timestamp: i64,
+}
+
+/// Emitted when a journal entry is permanently deleted.
+#[event]
+pub struct EntryDeleted {
+    /// The owner of the entry.
+    pub owner: Pubkey,
+    /// The journal entry account, which no longer exists.
+    pub entry: Pubkey,
+    /// The title of the entry.
+    pub title: String,
+    /// The Unix timestamp of the deletion.
+    pub timestamp: i64,
+}
+
+/// Emitted when a prompt pack is purchased.
+#[event]
+pub struct PromptPackPurchased {
+    /// The prompt pack that was purchased.
+    pub pack: Pubkey,
+    /// The wallet that bought the pack.
+    pub buyer: Pubkey,
+    /// The lamports paid to the pack's creator.
+    pub creator_amount: u64,
+    /// The lamports paid to the treasury.
+    pub treasury_amount: u64,
+}
+
+/// Emitted when a witness co-signs an entry's content.
+#[event]
+pub struct EntryWitnessed {
+    /// The witness that signed.
+    pub witness: Pubkey,
+    /// The journal entry that was witnessed.
+    pub entry: Pubkey,
+    /// The hash of the entry's title and body that was signed.
+    pub content_hash: [u8; 32],
+    /// The Unix timestamp of the signature.
+    pub timestamp: i64,
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct AcceptInvitation<'info> {
+    /// The shared journal being joined.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from its creator and identifier.
+    /// - `bump`: The bump stored in the journal.
+    #[account(
+        mut,
+        seeds = [
+            SHARED_JOURNAL_SEED.as_ref(),
+            shared_journal.creator.as_ref(),
+            &shared_journal.id.to_le_bytes(),
+        ],
+        bump = shared_journal.bump,
+    )]
+    pub shared_journal: Account<'info, SharedJournal>,
+    /// The invitation being accepted.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from the journal and the invitee's public key.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `has_one`: The invitation must name the inviter receiving its rent.
+    /// - `close`: Closes the account and refunds its rent to the inviter.
+    #[account(
+        mut,
+        seeds = [
+            INVITATION_SEED.as_ref(),
+            shared_journal.key().as_ref(),
+            invitee.key().as_ref(),
+        ],
+        bump,
+        has_one = inviter,
+        close = inviter,
+    )]
+    pub invitation: Account<'info, Invitation>,
+    /// The invitee's membership.
+    ///
+    /// - `init`: Creates the membership account.
+    /// - `seeds`: A unique identifier for the account, derived from the journal and the member's public key.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The invitee pays for their own membership.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init,
+        seeds = [ROLE_SEED.as_ref(), shared_journal.key().as_ref(), invitee.key().as_ref()],
+        bump,
+        payer = invitee,
+        space = 8 + JournalMember::INIT_SPACE
+    )]
+    pub membership: Account<'info, JournalMember>,
+    /// The invitee signing the transaction.
+    /// This account must sign the transaction to authorize it.
+    #[account(mut)]
+    pub invitee: Signer<'info>,
+    /// The admin who sent the invitation, who receives its rent.
+    #[account(mut)]
+    pub inviter: SystemAccount<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The system program required for account creation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<AcceptInvitation>) -> Result<()> {
+    let shared_journal = &mut ctx.accounts.shared_journal;
+    shared_journal.member_count += 1;
+    ctx.accounts.membership.set_inner(JournalMember {
+        journal: shared_journal.key(),
+        member: ctx.accounts.invitee.key(),
+        role: ctx.accounts.invitation.role,
+        joined_at: Clock::get()?.unix_timestamp,
+        entry_count: 0,
+    });
+
+    msg!(
+        "Member {} joined shared journal",
+        ctx.accounts.invitee.key()
+    );
+
+    Ok(())
+}
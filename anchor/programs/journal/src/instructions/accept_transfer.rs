@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct AcceptTransfer<'info> {
+    /// The journal entry being transferred.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from the entry's recorded author and index.
+    /// - `bump`: The bump stored in the entry.
+    #[account(
+        mut,
+        seeds = [
+            ENTRY_SEED.as_ref(),
+            journal_entry.author.as_ref(),
+            &journal_entry.index.to_le_bytes(),
+        ],
+        bump = journal_entry.bump,
+    )]
+    pub journal_entry: Account<'info, JournalEntryState>,
+    /// The wallet the entry was offered to.
+    /// This account must sign the transaction to authorize it.
+    pub new_owner: Signer<'info>,
+    /// The entry's audit log, required only if auditing is enabled for the entry.
+    #[account(mut, seeds = [AUDIT_LOG_SEED.as_ref(), journal_entry.key().as_ref()], bump)]
+    pub audit_log: Option<Account<'info, AuditLog>>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<AcceptTransfer>) -> Result<()> {
+    let journal_entry = &mut ctx.accounts.journal_entry;
+    let new_owner = ctx.accounts.new_owner.key();
+    require!(
+        journal_entry.pending_owner == Some(new_owner),
+        JournalError::NotPendingOwner
+    );
+    journal_entry.check_not_trashed()?;
+
+    let previous_owner = journal_entry.owner;
+    journal_entry.owner = new_owner;
+    journal_entry.pending_owner = None;
+    journal_entry.audit(
+        ctx.accounts.audit_log.as_mut(),
+        new_owner,
+        AuditAction::Transferred,
+    )?;
+
+    emit!(EntryTransferred {
+        from: previous_owner,
+        to: new_owner,
+        entry: journal_entry.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Journal entry transferred to {}", new_owner);
+
+    Ok(())
+}
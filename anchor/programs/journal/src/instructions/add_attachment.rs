@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct AddAttachment<'info> {
+    /// The journal entry to attach a file to.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from the entry's recorded author and index.
+    /// - `bump`: The bump stored in the entry.
+    /// - `has_one`: The signer must be the entry's owner, otherwise `NotOwner` is returned.
+    #[account(
+        mut,
+        seeds = [
+            ENTRY_SEED.as_ref(),
+            journal_entry.author.as_ref(),
+            &journal_entry.index.to_le_bytes(),
+        ],
+        bump = journal_entry.bump,
+        has_one = owner @ JournalError::NotOwner,
+    )]
+    pub journal_entry: Account<'info, JournalEntryState>,
+    /// The signer of the transaction.
+    /// This account must sign the transaction to authorize it.
+    pub owner: Signer<'info>,
+    /// The entry's audit log, required only if auditing is enabled for the entry.
+    #[account(mut, seeds = [AUDIT_LOG_SEED.as_ref(), journal_entry.key().as_ref()], bump)]
+    pub audit_log: Option<Account<'info, AuditLog>>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<AddAttachment>, cid: String) -> Result<()> {
+    validate_attachment(&cid)?;
+
+    let journal_entry = &mut ctx.accounts.journal_entry;
+    journal_entry.check_editable()?;
+    require!(
+        journal_entry.attachments.len() < MAX_ATTACHMENTS as usize,
+        JournalError::TooManyAttachments
+    );
+
+    msg!("Attachment added: {}", cid);
+    journal_entry.attachments.push(cid);
+    journal_entry.audit(
+        ctx.accounts.audit_log.as_mut(),
+        ctx.accounts.owner.key(),
+        AuditAction::MetadataUpdated,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ApproveMultisigAction<'info> {
+    /// The multisig journal the proposal belongs to.
+    ///
+    /// - `seeds`: A unique identifier for the account, derived from its creator and identifier.
+    /// - `bump`: The bump stored in the journal.
+    #[account(
+        seeds = [
+            MULTISIG_SEED.as_ref(),
+            multisig.creator.as_ref(),
+            &multisig.id.to_le_bytes(),
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, MultisigJournal>,
+    /// The proposal being approved.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `has_one`: The proposal must belong to the journal, otherwise `ProposalMismatch` is returned.
+    #[account(mut, has_one = multisig @ JournalError::ProposalMismatch)]
+    pub proposal: Account<'info, MultisigProposal>,
+    /// The owner approving the proposal.
+    /// This account must sign the transaction to authorize it.
+    pub owner: Signer<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<ApproveMultisigAction>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.check_not_expired()?;
+    proposal.approvals |= ctx
+        .accounts
+        .multisig
+        .approval_bit(&ctx.accounts.owner.key())?;
+
+    msg!(
+        "Multisig proposal approved by {} of {} required owners",
+        proposal.approvals.count_ones(),
+        ctx.accounts.multisig.threshold
+    );
+
+    Ok(())
+}
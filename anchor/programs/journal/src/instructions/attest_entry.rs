@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct AttestEntry<'info> {
+    /// The journal entry being attested.
+    pub journal_entry: Account<'info, JournalEntryState>,
+    /// The attestation to be created for the entry.
+    ///
+    /// - `init`: Creates the account; attesting the same entry twice fails.
+    /// - `seeds`: A unique identifier for the account, derived from the entry and attester's public keys.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init,
+        seeds = [ATTESTATION_SEED.as_ref(), journal_entry.key().as_ref(), attester.key().as_ref()],
+        bump,
+        payer = attester,
+        space = 8 + Attestation::INIT_SPACE
+    )]
+    pub attestation: Account<'info, Attestation>,
+    /// The attester signing the transaction.
+    /// This account must sign the transaction to authorize it.
+    #[account(mut)]
+    pub attester: Signer<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The system program required for account creation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<AttestEntry>,
+    role: u8,
+    statement_hash: Option<[u8; 32]>,
+) -> Result<()> {
+    ctx.accounts.journal_entry.check_published()?;
+
+    let attestation = &mut ctx.accounts.attestation;
+    attestation.attester = ctx.accounts.attester.key();
+    attestation.entry = ctx.accounts.journal_entry.key();
+    attestation.role = role;
+    attestation.statement_hash = statement_hash;
+    attestation.attested_at = Clock::get()?.unix_timestamp;
+
+    msg!("Entry attested with role {}", role);
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct BuyPromptPack<'info> {
+    /// The prompt pack being purchased.
+    ///
+    /// - `has_one`: The creator account must be the pack's creator.
+    #[account(has_one = creator)]
+    pub prompt_pack: Account<'info, PromptPack>,
+    /// The access record granted to the buyer.
+    ///
+    /// - `init`: Creates the account; buying the same pack twice fails.
+    /// - `seeds`: A unique identifier for the account, derived from the pack and buyer's public keys.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init,
+        seeds = [PROMPT_ACCESS_SEED.as_ref(), prompt_pack.key().as_ref(), buyer.key().as_ref()],
+        bump,
+        payer = buyer,
+        space = 8 + PromptPackAccess::INIT_SPACE
+    )]
+    pub access: Account<'info, PromptPackAccess>,
+    /// The buyer signing the transaction.
+    /// This account must sign the transaction to authorize the payment.
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    /// The pack's creator, who receives the price minus the treasury fee.
+    #[account(mut)]
+    pub creator: SystemAccount<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The treasury receiving the fee.
+    ///
+    /// - `address`: Must match the treasury in the program configuration.
+    #[account(mut, address = cpi_guard.config.treasury @ JournalError::WrongTreasury)]
+    pub treasury: SystemAccount<'info>,
+    /// The system program required for account creation and the lamport transfers.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> BuyPromptPack<'info> {
+    fn pay(&self, to: AccountInfo<'info>, amount: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        system_program::transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: self.buyer.to_account_info(),
+                    to,
+                },
+            ),
+            amount,
+        )
+    }
+}
+
+pub(crate) fn handler(ctx: Context<BuyPromptPack>) -> Result<()> {
+    let price = ctx.accounts.prompt_pack.price;
+    let (treasury_amount, creator_amount) = ctx.accounts.cpi_guard.config.split_fee(price);
+
+    ctx.accounts
+        .pay(ctx.accounts.creator.to_account_info(), creator_amount)?;
+    ctx.accounts
+        .pay(ctx.accounts.treasury.to_account_info(), treasury_amount)?;
+
+    let access = &mut ctx.accounts.access;
+    access.buyer = ctx.accounts.buyer.key();
+    access.pack = ctx.accounts.prompt_pack.key();
+    access.price_paid = price;
+    access.purchased_at = Clock::get()?.unix_timestamp;
+
+    emit!(PromptPackPurchased {
+        pack: ctx.accounts.prompt_pack.key(),
+        buyer: ctx.accounts.buyer.key(),
+        creator_amount,
+        treasury_amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CancelInvitation<'info> {
+    /// The invitation to be cancelled.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from the journal and the invitee's public key.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `has_one`: The invitation must name the inviter receiving its rent.
+    /// - `close`: Closes the account and refunds its rent to the inviter.
+    #[account(
+        mut,
+        seeds = [
+            INVITATION_SEED.as_ref(),
+            invitation.journal.as_ref(),
+            invitation.invitee.as_ref(),
+        ],
+        bump,
+        has_one = inviter,
+        close = inviter,
+    )]
+    pub invitation: Account<'info, Invitation>,
+    /// The admin who sent the invitation, who receives its rent.
+    #[account(mut)]
+    pub inviter: SystemAccount<'info>,
+    /// The inviter or the invitee, declining it.
+    /// This account must sign the transaction to authorize it.
+    pub signer: Signer<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<CancelInvitation>) -> Result<()> {
+    let invitation = &ctx.accounts.invitation;
+    let signer = ctx.accounts.signer.key();
+    require!(
+        signer == invitation.inviter || signer == invitation.invitee,
+        JournalError::NotInvitationParty
+    );
+
+    msg!("Invitation of {} cancelled", invitation.invitee);
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CancelMultisigProposal<'info> {
+    /// The multisig journal the proposal belongs to.
+    ///
+    /// - `seeds`: A unique identifier for the account, derived from its creator and identifier.
+    /// - `bump`: The bump stored in the journal.
+    #[account(
+        seeds = [
+            MULTISIG_SEED.as_ref(),
+            multisig.creator.as_ref(),
+            &multisig.id.to_le_bytes(),
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, MultisigJournal>,
+    /// The proposal being cancelled.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `has_one`: The proposal must belong to the journal, otherwise `ProposalMismatch` is returned.
+    /// - `close`: Closes the account and refunds its rent to the proposer.
+    #[account(
+        mut,
+        has_one = multisig @ JournalError::ProposalMismatch,
+        has_one = proposer,
+        close = proposer,
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+    /// The proposer, who receives the proposal's rent.
+    #[account(mut)]
+    pub proposer: SystemAccount<'info>,
+    /// The owner cancelling the proposal.
+    /// This account must sign the transaction to authorize it.
+    pub owner: Signer<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<CancelMultisigProposal>) -> Result<()> {
+    ctx.accounts
+        .proposal
+        .check_cancellable(&ctx.accounts.multisig, &ctx.accounts.owner.key())?;
+
+    msg!(
+        "Multisig proposal cancelled by {}",
+        ctx.accounts.owner.key()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ClaimInheritedJournal<'info> {
+    /// The profile of the inactive owner.
+    ///
+    /// - `seeds`: A unique identifier for the account, derived from the profile's recorded owner.
+    /// - `bump`: The bump stored in the profile.
+    ///
+    /// The owner's entries to claim are passed as writable remaining accounts, each followed by
+    /// its audit log if it is audited, then by its journal if it is filed in one.
+    #[account(
+        seeds = [PROFILE_SEED.as_ref(), profile.owner.as_ref()],
+        bump = profile.bump,
+    )]
+    pub profile: Account<'info, UserProfile>,
+    /// The beneficiary named in the profile.
+    /// This account must sign the transaction to authorize it.
+    pub beneficiary: Signer<'info>,
+    /// The guardian of the protected entries in the batch, required only if there are any.
+    /// Entries with different guardians are claimed in separate batches.
+    pub guardian: Option<Signer<'info>>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+}
+
+pub(crate) fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimInheritedJournal<'info>>,
+) -> Result<()> {
+    let profile = &ctx.accounts.profile;
+    let beneficiary = ctx.accounts.beneficiary.key();
+    require!(
+        profile.beneficiary == Some(beneficiary),
+        JournalError::NotBeneficiary
+    );
+    let now = Clock::get()?.unix_timestamp;
+    profile.check_inactive(now)?;
+
+    let mut claimed = 0;
+    let mut accounts = ctx.remaining_accounts.iter();
+    while let Some(info) = accounts.next() {
+        require!(info.is_writable, ErrorCode::ConstraintMut);
+        let mut journal_entry = Account::<JournalEntryState>::try_from(info)?;
+        require_keys_eq!(journal_entry.owner, profile.owner, JournalError::NotOwner);
+        // The owner meant trashed entries to go away, so they are left to be purged.
+        journal_entry.check_not_trashed()?;
+        journal_entry.check_guardian(ctx.accounts.guardian.as_ref())?;
+        journal_entry.owner = beneficiary;
+        // A transfer offered by the previous owner must not override the inheritance.
+        journal_entry.pending_owner = None;
+        journal_entry.audit_next(
+            journal_entry.key(),
+            &mut accounts,
+            beneficiary,
+            AuditAction::Transferred,
+        )?;
+        // Journals belong to the previous owner, so the entry returns to the default journal.
+        if journal_entry.journal.is_some() {
+            let journal_info = accounts.next().ok_or(JournalError::JournalRequired)?;
+            require!(journal_info.is_writable, ErrorCode::ConstraintMut);
+            let mut journal = Account::<Journal>::try_from(journal_info)?;
+            journal_entry.leave_journal(Some(&mut journal))?;
+            journal.exit(ctx.program_id)?;
+        }
+        journal_entry.exit(ctx.program_id)?;
+        claimed += 1;
+
+        emit!(EntryTransferred {
+            from: profile.owner,
+            to: beneficiary,
+            entry: journal_entry.key(),
+            timestamp: now,
+        });
+    }
+
+    msg!(
+        "{} journal entries of {} inherited by {}",
+        claimed,
+        profile.owner,
+        beneficiary
+    );
+
+    Ok(())
+}
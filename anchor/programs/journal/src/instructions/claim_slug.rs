@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(slug: String)]
+pub struct ClaimSlug<'info> {
+    /// The slug account to be created.
+    ///
+    /// - `init`: Creates the account; claiming a slug that is already held fails.
+    /// - `seeds`: A unique identifier for the account, derived from the slug.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init,
+        seeds = [SLUG_SEED.as_ref(), slug.as_bytes()],
+        bump,
+        payer = owner,
+        space = 8 + SlugRecord::INIT_SPACE
+    )]
+    pub slug_record: Account<'info, SlugRecord>,
+    /// The wallet claiming the slug.
+    /// This account must sign the transaction to authorize it.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// CHECK: The account the slug resolves to. The handler checks that it is a profile, journal
+    /// or table of contents held by the owner.
+    #[account(owner = crate::ID @ JournalError::InvalidSlugTarget)]
+    pub target: UncheckedAccount<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The treasury receiving the claim fee.
+    ///
+    /// - `address`: Must match the treasury in the program configuration.
+    #[account(mut, address = cpi_guard.config.treasury @ JournalError::WrongTreasury)]
+    pub treasury: SystemAccount<'info>,
+    /// The system program required for account creation and the fee transfer.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<ClaimSlug>, slug: String) -> Result<()> {
+    SlugRecord::validate(&slug)?;
+    SlugRecord::check_target(&ctx.accounts.target, ctx.accounts.owner.key())?;
+
+    let fee = ctx.accounts.cpi_guard.config.slug_claim_fee;
+    if fee > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            fee,
+        )?;
+    }
+
+    msg!("Slug claimed: {}", slug);
+
+    let slug_record = &mut ctx.accounts.slug_record;
+    slug_record.owner = ctx.accounts.owner.key();
+    slug_record.target = ctx.accounts.target.key();
+    slug_record.slug = slug;
+    slug_record.claimed_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
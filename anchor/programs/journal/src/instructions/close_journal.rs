@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CloseJournal<'info> {
+    /// The journal to be closed.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from the owner's public key and the journal name.
+    /// - `bump`: The bump stored in the journal.
+    /// - `has_one`: The signer must be the journal's owner, otherwise `NotOwner` is returned.
+    ///
+    /// A page of the journal's entries is passed as writable remaining accounts, each audited
+    /// entry followed by its audit log.
+    #[account(
+        mut,
+        seeds = [JOURNAL_SEED.as_ref(), owner.key().as_ref(), journal.name.as_bytes()],
+        bump = journal.bump,
+        has_one = owner @ JournalError::NotOwner,
+    )]
+    pub journal: Account<'info, Journal>,
+    /// The owner of the journal, who receives its rent.
+    /// This account must sign the transaction to authorize it.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// The owner's profile, whose statistics are updated for the entries they wrote.
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED.as_ref(), owner.key().as_ref()],
+        bump = profile.bump,
+    )]
+    pub profile: Account<'info, UserProfile>,
+    /// The global statistics shard of the entry's author, updated with the entry count.
+    #[account(
+        mut,
+        seeds = [GLOBAL_STATS_SEED.as_ref(), &[GlobalStats::shard_of(&owner.key())]],
+        bump = global_stats.bump,
+    )]
+    pub global_stats: Account<'info, GlobalStats>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+}
+
+pub(crate) fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CloseJournal<'info>>,
+) -> Result<()> {
+    let owner = ctx.accounts.owner.to_account_info();
+    let journal = &mut ctx.accounts.journal;
+    let now = Clock::get()?.unix_timestamp;
+
+    let mut trashed = 0;
+    let mut accounts = ctx.remaining_accounts.iter();
+    while let Some(info) = accounts.next() {
+        require!(info.is_writable, ErrorCode::ConstraintMut);
+        let mut journal_entry = Account::<JournalEntryState>::try_from(info)?;
+        journal_entry.leave_journal(Some(journal))?;
+        // Entries written or owned by someone else, protected from deletion or already in the
+        // trash are only taken out of the journal.
+        let deletable = journal_entry.owner == owner.key()
+            && journal_entry.author == owner.key()
+            && journal_entry.deleted_at.is_none()
+            && !journal_entry.is_sealed
+            && journal_entry.guardian.is_none()
+            && !matches!(journal_entry.unlock_at, Some(unlock_at) if now < unlock_at);
+        if deletable {
+            // Closing the entry would require unlinking it from its author's entry list, so it
+            // goes to the trash and is purged from there.
+            journal_entry.deleted_at = Some(now);
+            ctx.accounts.profile.record_entry_trashed();
+            ctx.accounts.global_stats.record_entry_removed();
+            journal_entry.audit_next(
+                journal_entry.key(),
+                &mut accounts,
+                owner.key(),
+                AuditAction::Deleted,
+            )?;
+            emit!(EntryTrashed {
+                owner: journal_entry.owner,
+                entry: journal_entry.key(),
+                title: journal_entry.title.clone(),
+                timestamp: now,
+            });
+            trashed += 1;
+        }
+        journal_entry.exit(ctx.program_id)?;
+    }
+
+    if trashed > 0 {
+        ctx.accounts.profile.record_activity(owner.key(), now);
+    }
+
+    msg!(
+        "Moved {} journal entries of journal {} to the trash",
+        trashed,
+        journal.name
+    );
+
+    // Once its last entry is gone, the journal itself is closed.
+    if journal.entry_count == 0 {
+        journal.close(owner)?;
+        msg!("Journal closed");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::*;
+use crate::state::*;
+
+/// The context for the `commit_entry` function.
+#[derive(Accounts)]
+pub struct CommitEntry<'info> {
+    /// The owner's profile, holding the index of the next entry.
+    ///
+    /// - `init_if_needed`: Creates the profile with the owner's first entry.
+    /// - `seeds`: A unique identifier for the account, derived from the owner's public key.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init_if_needed,
+        seeds = [PROFILE_SEED.as_ref(), owner.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + UserProfile::INIT_SPACE
+    )]
+    pub profile: Account<'info, UserProfile>,
+    /// The account to be created for the journal entry.
+    ///
+    /// - `init`: Creates the account at the next free index.
+    /// - `seeds`: A unique identifier for the account, derived from the owner's public key and the next entry index.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: The size of an empty entry; the content is allocated when revealed.
+    #[account(
+        init,
+        seeds = [
+            ENTRY_SEED.as_ref(),
+            owner.key().as_ref(),
+            &profile.entry_count.to_le_bytes(),
+        ],
+        bump,
+        payer = payer,
+        space = JournalEntryState::space(0, 0)
+    )]
+    pub journal_entry: Account<'info, JournalEntryState>,
+    /// The entry index page the new entry is listed on.
+    ///
+    /// - `init_if_needed`: Creates the page with its first entry.
+    /// - `seeds`: A unique identifier for the account, derived from the owner's public key and the page listing the next entry index.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init_if_needed,
+        seeds = [
+            ENTRY_INDEX_SEED.as_ref(),
+            owner.key().as_ref(),
+            &EntryIndexPage::page_of(profile.entry_count).to_le_bytes(),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + EntryIndexPage::INIT_SPACE
+    )]
+    pub index_page: Account<'info, EntryIndexPage>,
+    /// The signer of the transaction.
+    /// This account must sign the transaction to authorize it. It may be a program-derived
+    /// address signing through CPI, as it does not pay for anything.
+    pub owner: Signer<'info>,
+    /// The account paying rent for account creation and reallocation, and receiving the refund
+    /// when the entry shrinks. Usually the owner itself.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The global statistics shard of the entry's author, updated with the entry count.
+    ///
+    /// - `init_if_needed`: Creates the shard with the first entry it counts.
+    /// - `seeds`: A unique identifier for the account, derived from the shard number of the author's public key.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init_if_needed,
+        seeds = [GLOBAL_STATS_SEED.as_ref(), &[GlobalStats::shard_of(&owner.key())]],
+        bump,
+        payer = payer,
+        space = 8 + GlobalStats::INIT_SPACE
+    )]
+    pub global_stats: Account<'info, GlobalStats>,
+    /// The profile owner's latest entry, required only if they have entries, so the new entry
+    /// can be linked after it.
+    #[account(mut)]
+    pub tail_entry: Option<Account<'info, JournalEntryState>>,
+    /// The system program required for account creation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<CommitEntry>, commitment: [u8; 32]) -> Result<()> {
+    msg!("Journal Entry Committed");
+
+    let journal_entry = &mut ctx.accounts.journal_entry;
+    journal_entry.owner = ctx.accounts.owner.key();
+    journal_entry.author = journal_entry.owner;
+    journal_entry.index = ctx
+        .accounts
+        .profile
+        .next_entry_index(journal_entry.owner, ctx.bumps.profile);
+    journal_entry.bump = ctx.bumps.journal_entry;
+    journal_entry.commitment = Some(commitment);
+    journal_entry.status = EntryStatus::Published;
+    journal_entry.touch(Clock::get()?.unix_timestamp);
+    let first_entry = ctx.accounts.profile.record_entry_created(journal_entry);
+    ctx.accounts
+        .profile
+        .record_activity(journal_entry.owner, journal_entry.created_at);
+    ctx.accounts
+        .global_stats
+        .record_entry_created(ctx.bumps.global_stats, first_entry);
+    ctx.accounts
+        .profile
+        .append_entry(journal_entry, ctx.accounts.tail_entry.as_mut())?;
+    ctx.accounts.profile.index_entry(
+        &mut ctx.accounts.index_page,
+        journal_entry,
+        ctx.bumps.index_page,
+    );
+
+    emit!(EntryCreated {
+        owner: journal_entry.owner,
+        entry: journal_entry.key(),
+        title: journal_entry.title.clone(),
+        timestamp: journal_entry.created_at,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,358 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(title: String, message: String, nonce: u64)]
+pub struct CreateEntryWithPermit<'info> {
+    /// The owner's profile, holding the index of the next entry.
+    ///
+    /// - `init_if_needed`: Creates the profile with the owner's first entry.
+    /// - `seeds`: A unique identifier for the account, derived from the owner's public key.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init_if_needed,
+        seeds = [PROFILE_SEED.as_ref(), owner.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + UserProfile::INIT_SPACE
+    )]
+    pub profile: Account<'info, UserProfile>,
+    /// The account to be created for the journal entry.
+    ///
+    /// - `init`: Creates the account at the next free index.
+    /// - `seeds`: A unique identifier for the account, derived from the owner's public key and the next entry index.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The relayer pays for the account creation.
+    /// - `space`: Exactly the size of the title and message; later edits realloc as needed.
+    #[account(
+        init,
+        seeds = [
+            ENTRY_SEED.as_ref(),
+            owner.key().as_ref(),
+            &profile.entry_count.to_le_bytes(),
+        ],
+        bump,
+        payer = payer,
+        space = JournalEntryState::space(title.len(), message.len())
+    )]
+    pub journal_entry: Account<'info, JournalEntryState>,
+    /// The entry index page the new entry is listed on.
+    ///
+    /// - `init_if_needed`: Creates the page with its first entry.
+    /// - `seeds`: A unique identifier for the account, derived from the owner's public key and the page listing the next entry index.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init_if_needed,
+        seeds = [
+            ENTRY_INDEX_SEED.as_ref(),
+            owner.key().as_ref(),
+            &EntryIndexPage::page_of(profile.entry_count).to_le_bytes(),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + EntryIndexPage::INIT_SPACE
+    )]
+    pub index_page: Account<'info, EntryIndexPage>,
+    /// The receipt marking the permit nonce as used.
+    ///
+    /// - `init`: Creates the account; reusing a nonce fails.
+    /// - `seeds`: A unique identifier for the account, derived from the owner's public key and the nonce.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The relayer pays for the account creation.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init,
+        seeds = [PERMIT_SEED.as_ref(), owner.key().as_ref(), &nonce.to_le_bytes()],
+        bump,
+        payer = payer,
+        space = 8 + PermitReceipt::INIT_SPACE
+    )]
+    pub permit_receipt: Account<'info, PermitReceipt>,
+    /// CHECK: The owner authorizes the entry through the Ed25519 signature verified in the handler.
+    pub owner: UncheckedAccount<'info>,
+    /// The relayer paying fees and rent.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The global statistics shard of the entry's author, updated with the entry count.
+    ///
+    /// - `init_if_needed`: Creates the shard with the first entry it counts.
+    /// - `seeds`: A unique identifier for the account, derived from the shard number of the author's public key.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init_if_needed,
+        seeds = [GLOBAL_STATS_SEED.as_ref(), &[GlobalStats::shard_of(&owner.key())]],
+        bump,
+        payer = payer,
+        space = 8 + GlobalStats::INIT_SPACE
+    )]
+    pub global_stats: Account<'info, GlobalStats>,
+    /// The profile owner's latest entry, required only if they have entries, so the new entry
+    /// can be linked after it.
+    #[account(mut)]
+    pub tail_entry: Option<Account<'info, JournalEntryState>>,
+    /// The system program required for account creation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<CreateEntryWithPermit>,
+    title: String,
+    message: String,
+    nonce: u64,
+    expiry: i64,
+) -> Result<()> {
+    ctx.accounts
+        .cpi_guard
+        .config
+        .validate_entry(&title, &message)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(now <= expiry, JournalError::PermitExpired);
+
+    let owner = ctx.accounts.owner.key();
+    let permit = EntryPermit {
+        program_id: crate::ID,
+        owner,
+        title: title.clone(),
+        message: message.clone(),
+        nonce,
+        expiry,
+    };
+    verify_ed25519_signature(
+        &ctx.accounts.cpi_guard.instructions,
+        &owner,
+        &permit.try_to_vec()?,
+    )?;
+
+    let permit_receipt = &mut ctx.accounts.permit_receipt;
+    permit_receipt.owner = owner;
+    permit_receipt.nonce = nonce;
+    permit_receipt.used_at = now;
+
+    msg!("Journal Entry Created with permit");
+    msg!("Title: {}", title);
+
+    let journal_entry = &mut ctx.accounts.journal_entry;
+    journal_entry.owner = owner;
+    journal_entry.author = owner;
+    journal_entry.index = ctx
+        .accounts
+        .profile
+        .next_entry_index(owner, ctx.bumps.profile);
+    journal_entry.bump = ctx.bumps.journal_entry;
+    journal_entry.title = title;
+    journal_entry.message = message;
+    journal_entry.status = EntryStatus::Published;
+    journal_entry.touch(now);
+    let first_entry = ctx.accounts.profile.record_entry_created(journal_entry);
+    ctx.accounts
+        .profile
+        .record_activity(journal_entry.owner, journal_entry.created_at);
+    ctx.accounts
+        .global_stats
+        .record_entry_created(ctx.bumps.global_stats, first_entry);
+    ctx.accounts
+        .profile
+        .append_entry(journal_entry, ctx.accounts.tail_entry.as_mut())?;
+    ctx.accounts.profile.index_entry(
+        &mut ctx.accounts.index_page,
+        journal_entry,
+        ctx.bumps.index_page,
+    );
+
+    emit!(EntryCreated {
+        owner: journal_entry.owner,
+        entry: journal_entry.key(),
+        title: journal_entry.title.clone(),
+        timestamp: journal_entry.created_at,
+    });
+
+    Ok(())
+}
+
+/// Verifies that the instruction preceding the current one is an Ed25519 program instruction
+/// checking a single signature by `signer` over exactly `message`.
+///
+/// The Ed25519 program fails the whole transaction if the signature is invalid, so it is enough
+/// to confirm that it was asked to verify the expected key and message.
+pub fn verify_ed25519_signature(
+    instructions: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let current_index = instructions_sysvar::load_current_index_checked(instructions)?;
+    require!(current_index > 0, JournalError::InvalidPermitSignature);
+    let ix =
+        instructions_sysvar::load_instruction_at_checked(current_index as usize - 1, instructions)?;
+    require_keys_eq!(
+        ix.program_id,
+        ed25519_program::ID,
+        JournalError::InvalidPermitSignature
+    );
+
+    // Layout: signature count, padding, then one 14-byte offsets struct per signature.
+    let data = &ix.data;
+    require!(
+        data.len() >= 16 && data[0] == 1,
+        JournalError::InvalidPermitSignature
+    );
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]) as usize;
+    let public_key_offset = read_u16(6);
+    let message_offset = read_u16(10);
+    let message_size = read_u16(12);
+    // All parts must live in the Ed25519 instruction itself, marked by u16::MAX.
+    for index_at in [4, 8, 14] {
+        require!(
+            read_u16(index_at) == u16::MAX as usize,
+            JournalError::InvalidPermitSignature
+        );
+    }
+
+    let public_key = data
+        .get(public_key_offset..public_key_offset + 32)
+        .ok_or(JournalError::InvalidPermitSignature)?;
+    let signed_message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(JournalError::InvalidPermitSignature)?;
+    require!(
+        public_key == signer.as_ref() && signed_message == message,
+        JournalError::InvalidPermitSignature
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::sysvar::{self, instructions::BorrowedInstruction};
+
+    /// Builds Ed25519 program data for one signature with every part stored inline.
+    fn ed25519_data(signer: &Pubkey, message: &[u8]) -> Vec<u8> {
+        let (public_key_offset, signature_offset, message_offset) = (16u16, 48u16, 112u16);
+        let mut data = vec![1, 0];
+        for value in [
+            signature_offset,
+            u16::MAX,
+            public_key_offset,
+            u16::MAX,
+            message_offset,
+            message.len() as u16,
+            u16::MAX,
+        ] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(signer.as_ref());
+        data.extend_from_slice(&[0; 64]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    /// Serializes an instructions sysvar holding `ed25519_ix` followed by the current instruction.
+    fn sysvar_data(program_id: &Pubkey, ed25519_ix: &[u8], current_index: u16) -> Vec<u8> {
+        let instructions = [
+            BorrowedInstruction {
+                program_id,
+                accounts: Vec::new(),
+                data: ed25519_ix,
+            },
+            BorrowedInstruction {
+                program_id: &crate::ID,
+                accounts: Vec::new(),
+                data: &[],
+            },
+        ];
+        let mut data = instructions_sysvar::construct_instructions_data(&instructions);
+        instructions_sysvar::store_current_index(&mut data, current_index);
+        data
+    }
+
+    fn verify(mut data: Vec<u8>, signer: &Pubkey, message: &[u8]) -> Result<()> {
+        let key = sysvar::instructions::ID;
+        let owner = sysvar::ID;
+        let mut lamports = 0;
+        let info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+        verify_ed25519_signature(&info, signer, message)
+    }
+
+    fn assert_invalid(result: Result<()>) {
+        assert_eq!(result, Err(JournalError::InvalidPermitSignature.into()));
+    }
+
+    #[test]
+    fn accepts_a_matching_signature() {
+        let signer = Pubkey::new_unique();
+        let ix = ed25519_data(&signer, b"permit");
+        let data = sysvar_data(&ed25519_program::ID, &ix, 1);
+        assert!(verify(data, &signer, b"permit").is_ok());
+    }
+
+    #[test]
+    fn rejects_another_signer_or_message() {
+        let signer = Pubkey::new_unique();
+        let ix = ed25519_data(&signer, b"permit");
+        let data = sysvar_data(&ed25519_program::ID, &ix, 1);
+        assert_invalid(verify(data.clone(), &Pubkey::new_unique(), b"permit"));
+        assert_invalid(verify(data.clone(), &signer, b"permit!"));
+        assert_invalid(verify(data, &signer, b"perm"));
+    }
+
+    #[test]
+    fn rejects_a_missing_ed25519_instruction() {
+        let signer = Pubkey::new_unique();
+        let ix = ed25519_data(&signer, b"permit");
+        let first = sysvar_data(&ed25519_program::ID, &ix, 0);
+        assert_invalid(verify(first, &signer, b"permit"));
+        let other_program = sysvar_data(&crate::ID, &ix, 1);
+        assert_invalid(verify(other_program, &signer, b"permit"));
+    }
+
+    #[test]
+    fn rejects_malformed_ed25519_data() {
+        let signer = Pubkey::new_unique();
+        let valid = ed25519_data(&signer, b"permit");
+
+        let mut two_signatures = valid.clone();
+        two_signatures[0] = 2;
+        // The message's instruction index points at another instruction.
+        let mut external_message = valid.clone();
+        external_message[14..16].copy_from_slice(&1u16.to_le_bytes());
+        // The message size runs past the end of the data.
+        let mut overrun = valid.clone();
+        overrun[12..14].copy_from_slice(&64u16.to_le_bytes());
+
+        for ix in [
+            two_signatures,
+            external_message,
+            overrun,
+            valid[..15].to_vec(),
+        ] {
+            let data = sysvar_data(&ed25519_program::ID, &ix, 1);
+            assert_invalid(verify(data, &signer, b"permit"));
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateJournal<'info> {
+    /// The journal to be created.
+    ///
+    /// - `init`: Creates the account; reusing a name fails.
+    /// - `seeds`: A unique identifier for the account, derived from the owner's public key and the journal name.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init,
+        seeds = [JOURNAL_SEED.as_ref(), owner.key().as_ref(), name.as_bytes()],
+        bump,
+        payer = owner,
+        space = 8 + Journal::INIT_SPACE
+    )]
+    pub journal: Account<'info, Journal>,
+    /// The signer of the transaction.
+    /// This account must sign the transaction to authorize it.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The system program required for account creation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<CreateJournal>,
+    name: String,
+    description: String,
+) -> Result<()> {
+    Journal::validate(&name, &description)?;
+
+    msg!("Journal {} created", name);
+
+    ctx.accounts.journal.set_inner(Journal {
+        owner: ctx.accounts.owner.key(),
+        name,
+        description,
+        entry_count: 0,
+        bump: ctx.bumps.journal,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,200 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::events::*;
+use crate::state::*;
+
+/// The context for the `create_journal_entry` function.
+/// The `#[derive(Accounts)]` attribute macro defines the accounts required for the function.
+#[derive(Accounts)]
+#[instruction(title: String, message: String)]
+pub struct CreateEntry<'info> {
+    /// The owner's profile, holding the index of the next entry.
+    ///
+    /// - `init_if_needed`: Creates the profile with the owner's first entry.
+    /// - `seeds`: A unique identifier for the account, derived from the owner's public key.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init_if_needed,
+        seeds = [PROFILE_SEED.as_ref(), owner.key().as_ref()],
+        bump,
+        payer = payer,
+        space = 8 + UserProfile::INIT_SPACE
+    )]
+    pub profile: Account<'info, UserProfile>,
+    /// The account to be created for the journal entry.
+    ///
+    /// - `init`: Creates the account at the next free index.
+    /// - `seeds`: A unique identifier for the account, derived from the owner's public key and the next entry index.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: Exactly the size of the title and message; later edits realloc as needed.
+    #[account(
+        init,
+        seeds = [
+            ENTRY_SEED.as_ref(),
+            owner.key().as_ref(),
+            &profile.entry_count.to_le_bytes(),
+        ],
+        bump,
+        payer = payer,
+        space = JournalEntryState::space(title.len(), message.len())
+    )]
+    pub journal_entry: Account<'info, JournalEntryState>,
+    /// The entry index page the new entry is listed on.
+    ///
+    /// - `init_if_needed`: Creates the page with its first entry.
+    /// - `seeds`: A unique identifier for the account, derived from the owner's public key and the page listing the next entry index.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init_if_needed,
+        seeds = [
+            ENTRY_INDEX_SEED.as_ref(),
+            owner.key().as_ref(),
+            &EntryIndexPage::page_of(profile.entry_count).to_le_bytes(),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + EntryIndexPage::INIT_SPACE
+    )]
+    pub index_page: Account<'info, EntryIndexPage>,
+    /// The signer of the transaction.
+    /// This account must sign the transaction to authorize it. It may be a program-derived
+    /// address signing through CPI, as it does not pay for anything.
+    pub owner: Signer<'info>,
+    /// The account paying rent for account creation and reallocation, and receiving the refund
+    /// when the entry shrinks. Usually the owner itself.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// The named journal to file the entry in, or `None` for the owner's default journal.
+    #[account(mut, has_one = owner @ JournalError::NotOwner)]
+    pub journal: Option<Account<'info, Journal>>,
+    /// The prompt pack holding the prompt the entry answers, required only if it answers one.
+    pub prompt_pack: Option<Account<'info, PromptPack>>,
+    /// The owner's access to the prompt pack, required unless the owner created the pack.
+    pub prompt_access: Option<Account<'info, PromptPackAccess>>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The global statistics shard of the entry's author, updated with the entry count.
+    ///
+    /// - `init_if_needed`: Creates the shard with the first entry it counts.
+    /// - `seeds`: A unique identifier for the account, derived from the shard number of the author's public key.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init_if_needed,
+        seeds = [GLOBAL_STATS_SEED.as_ref(), &[GlobalStats::shard_of(&owner.key())]],
+        bump,
+        payer = payer,
+        space = 8 + GlobalStats::INIT_SPACE
+    )]
+    pub global_stats: Account<'info, GlobalStats>,
+    /// The profile owner's latest entry, required only if they have entries, so the new entry
+    /// can be linked after it.
+    #[account(mut)]
+    pub tail_entry: Option<Account<'info, JournalEntryState>>,
+    /// The system program required for account creation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<CreateEntry>,
+    title: String,
+    message: String,
+    options: EntryOptions,
+) -> Result<()> {
+    ctx.accounts
+        .cpi_guard
+        .config
+        .validate_entry(&title, &message)?;
+    options.content_type.validate(&message)?;
+    JournalEntryState::validate_tags(&options.tags)?;
+    JournalEntryState::validate_lang(options.lang)?;
+    if let Some(weather) = &options.weather {
+        weather.validate()?;
+    }
+
+    // Log messages to the Solana runtime, useful for debugging.
+    msg!("Journal Entry Created");
+    msg!("Title: {}", title);
+    msg!("Message: {}", message);
+
+    // Access the mutable reference to the journal entry account.
+    let journal_entry = &mut ctx.accounts.journal_entry;
+    // Set the owner of the journal entry to the public key of the transaction signer.
+    journal_entry.owner = ctx.accounts.owner.key();
+    journal_entry.author = journal_entry.owner;
+    // Claim the next index in the owner's journal.
+    journal_entry.index = ctx
+        .accounts
+        .profile
+        .next_entry_index(journal_entry.owner, ctx.bumps.profile);
+    journal_entry.bump = ctx.bumps.journal_entry;
+    if let Some(journal) = ctx.accounts.journal.as_mut() {
+        journal_entry.join_journal(journal);
+    }
+    // Set the title and message of the journal entry.
+    journal_entry.title = title;
+    journal_entry.message = message;
+    journal_entry.tags = options.tags;
+    journal_entry.category = options.category;
+    journal_entry.content_type = options.content_type;
+    journal_entry.lang = options.lang;
+    journal_entry.weather = options.weather;
+    if let Some(index) = options.prompt {
+        let prompt_pack = ctx
+            .accounts
+            .prompt_pack
+            .as_ref()
+            .ok_or(JournalError::PromptPackRequired)?;
+        journal_entry.prompt = Some(prompt_pack.reference(
+            prompt_pack.key(),
+            ctx.accounts.prompt_access.as_deref(),
+            journal_entry.owner,
+            index,
+        )?);
+    }
+    let now = Clock::get()?.unix_timestamp;
+    if let Some(publish_at) = options.publish_at {
+        require!(publish_at > now, JournalError::InvalidPublishTime);
+    }
+    // A scheduled entry stays a draft until its publish time.
+    journal_entry.status = if options.draft || options.publish_at.is_some() {
+        EntryStatus::Draft
+    } else {
+        EntryStatus::Published
+    };
+    journal_entry.publish_at = options.publish_at;
+    journal_entry.touch(now);
+    let first_entry = ctx.accounts.profile.record_entry_created(journal_entry);
+    ctx.accounts
+        .profile
+        .record_activity(journal_entry.owner, journal_entry.created_at);
+    ctx.accounts
+        .global_stats
+        .record_entry_created(ctx.bumps.global_stats, first_entry);
+    ctx.accounts
+        .profile
+        .append_entry(journal_entry, ctx.accounts.tail_entry.as_mut())?;
+    ctx.accounts.profile.index_entry(
+        &mut ctx.accounts.index_page,
+        journal_entry,
+        ctx.bumps.index_page,
+    );
+
+    emit!(EntryCreated {
+        owner: journal_entry.owner,
+        entry: journal_entry.key(),
+        title: journal_entry.title.clone(),
+        timestamp: journal_entry.created_at,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CreateMultisigJournal<'info> {
+    /// The multisig journal to be created.
+    ///
+    /// - `init`: Creates the account; reusing an identifier fails.
+    /// - `seeds`: A unique identifier for the account, derived from the creator's public key and the identifier.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init,
+        seeds = [MULTISIG_SEED.as_ref(), creator.key().as_ref(), &id.to_le_bytes()],
+        bump,
+        payer = creator,
+        space = 8 + MultisigJournal::INIT_SPACE
+    )]
+    pub multisig: Account<'info, MultisigJournal>,
+    /// The signer of the transaction, who need not be one of the owners.
+    /// This account must sign the transaction to authorize it.
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The system program required for account creation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<CreateMultisigJournal>,
+    id: u64,
+    owners: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    MultisigJournal::validate(&owners, threshold)?;
+
+    msg!(
+        "Multisig journal created with {} of {} owners",
+        threshold,
+        owners.len()
+    );
+
+    ctx.accounts.multisig.set_inner(MultisigJournal {
+        creator: ctx.accounts.creator.key(),
+        id,
+        owners,
+        threshold,
+        entry_count: 0,
+        proposal_count: 0,
+        bump: ctx.bumps.multisig,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CreateSharedJournal<'info> {
+    /// The shared journal to be created.
+    ///
+    /// - `init`: Creates the account; reusing an identifier fails.
+    /// - `seeds`: A unique identifier for the account, derived from the creator's public key and the identifier.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init,
+        seeds = [SHARED_JOURNAL_SEED.as_ref(), creator.key().as_ref(), &id.to_le_bytes()],
+        bump,
+        payer = creator,
+        space = 8 + SharedJournal::INIT_SPACE
+    )]
+    pub shared_journal: Account<'info, SharedJournal>,
+    /// The creator's membership, as the journal's first admin.
+    ///
+    /// - `init`: Creates the membership account.
+    /// - `seeds`: A unique identifier for the account, derived from the journal and the member's public key.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init,
+        seeds = [ROLE_SEED.as_ref(), shared_journal.key().as_ref(), creator.key().as_ref()],
+        bump,
+        payer = creator,
+        space = 8 + JournalMember::INIT_SPACE
+    )]
+    pub membership: Account<'info, JournalMember>,
+    /// The signer of the transaction.
+    /// This account must sign the transaction to authorize it.
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The system program required for account creation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<CreateSharedJournal>, id: u64) -> Result<()> {
+    let creator = ctx.accounts.creator.key();
+    ctx.accounts.shared_journal.set_inner(SharedJournal {
+        creator,
+        id,
+        member_count: 1,
+        bump: ctx.bumps.shared_journal,
+    });
+    ctx.accounts.membership.set_inner(JournalMember {
+        journal: ctx.accounts.shared_journal.key(),
+        member: creator,
+        role: MemberRole::Admin,
+        joined_at: Clock::get()?.unix_timestamp,
+        entry_count: 0,
+    });
+
+    msg!("Shared journal {} created", id);
+
+    Ok(())
+}
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::*;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct DelegateUpdateMessage<'info> {
+    /// The account to be updated for the journal entry.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from the entry's recorded author and index.
+    /// - `bump`: The bump stored in the entry.
+    ///
+    /// The handler resizes the account to fit the new message: the delegate pays for growth and
+    /// the owner receives the lamports freed by shrinking, including donated rent.
+    #[account(
+        mut,
+        seeds = [
+            ENTRY_SEED.as_ref(),
+            journal_entry.author.as_ref(),
+            &journal_entry.index.to_le_bytes(),
+        ],
+        bump = journal_entry.bump,
+    )]
+    pub journal_entry: Account<'info, JournalEntryState>,
+    /// The owner's delegation to the signer.
+    ///
+    /// - `seeds`: A unique identifier for the account, derived from the owner and delegate public keys.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    #[account(
+        seeds = [DELEGATE_SEED.as_ref(), journal_entry.owner.as_ref(), delegate.key().as_ref()],
+        bump,
+    )]
+    pub write_delegate: Account<'info, WriteDelegate>,
+    /// The delegate writing on the owner's behalf, paying fees and rent.
+    #[account(mut)]
+    pub delegate: Signer<'info>,
+    /// CHECK: The entry's owner, which only receives the lamports freed when the entry shrinks.
+    #[account(mut, address = journal_entry.owner)]
+    pub owner: UncheckedAccount<'info>,
+    /// The entry's audit log, required only if auditing is enabled for the entry.
+    #[account(mut, seeds = [AUDIT_LOG_SEED.as_ref(), journal_entry.key().as_ref()], bump)]
+    pub audit_log: Option<Account<'info, AuditLog>>,
+    /// The entry's revision history, required only if revisions are enabled for the entry.
+    #[account(mut, seeds = [REVISION_SEED.as_ref(), journal_entry.key().as_ref()], bump)]
+    pub revision_history: Option<Account<'info, RevisionHistory>>,
+    /// The profile of the entry's author, whose statistics are updated.
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED.as_ref(), journal_entry.author.as_ref()],
+        bump = profile.bump,
+    )]
+    pub profile: Account<'info, UserProfile>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The system program required for account reallocation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<DelegateUpdateMessage>,
+    message: String,
+    expected_version: u64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.write_delegate.check_update(now)?;
+    ctx.accounts.journal_entry.check_editable()?;
+    ctx.accounts.journal_entry.check_version(expected_version)?;
+
+    // Resized by hand rather than with `realloc`, whose payer would also receive the refund.
+    let entry_info = ctx.accounts.journal_entry.to_account_info();
+    let new_len = JournalEntryState::space(ctx.accounts.journal_entry.title.len(), message.len());
+    let minimum_balance = Rent::get()?.minimum_balance(new_len);
+    let lamports = entry_info.lamports();
+    if lamports < minimum_balance {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.delegate.to_account_info(),
+                    to: entry_info.clone(),
+                },
+            ),
+            minimum_balance - lamports,
+        )?;
+    } else if new_len < entry_info.data_len() {
+        let refund = lamports - minimum_balance;
+        entry_info.sub_lamports(refund)?;
+        ctx.accounts.owner.add_lamports(refund)?;
+    }
+    entry_info.realloc(new_len, true)?;
+
+    let journal_entry = &mut ctx.accounts.journal_entry;
+    ctx.accounts
+        .cpi_guard
+        .config
+        .validate_entry(&journal_entry.title, &message)?;
+    journal_entry.content_type.validate(&message)?;
+
+    msg!("Journal Entry Message Updated by delegate");
+    msg!("Title: {}", journal_entry.title);
+
+    journal_entry.record_revision(ctx.accounts.revision_history.as_mut(), &message)?;
+    journal_entry.message = message;
+    journal_entry.clear_encryption();
+    journal_entry.touch(now);
+    ctx.accounts
+        .profile
+        .record_bytes_written(journal_entry.message.len());
+    journal_entry.audit(
+        ctx.accounts.audit_log.as_mut(),
+        ctx.accounts.delegate.key(),
+        AuditAction::Updated,
+    )?;
+
+    emit!(EntryUpdated {
+        owner: journal_entry.owner,
+        entry: journal_entry.key(),
+        title: journal_entry.title.clone(),
+        timestamp: journal_entry.updated_at,
+    });
+
+    Ok(())
+}
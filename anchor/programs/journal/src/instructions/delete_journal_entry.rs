@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct DeleteEntry<'info> {
+    /// The journal entry to be moved to the trash.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from the entry's recorded author and index.
+    /// - `bump`: The bump stored in the entry.
+    /// - `has_one`: The signer must be the entry's owner, otherwise `NotOwner` is returned.
+    #[account(
+        mut,
+        seeds = [
+            ENTRY_SEED.as_ref(),
+            journal_entry.author.as_ref(),
+            &journal_entry.index.to_le_bytes(),
+        ],
+        bump = journal_entry.bump,
+        has_one = owner @ JournalError::NotOwner,
+    )]
+    pub journal_entry: Account<'info, JournalEntryState>,
+    /// The signer of the transaction.
+    /// This account must sign the transaction to authorize it.
+    pub owner: Signer<'info>,
+    /// The guardian of the entry, required only if the entry is protected.
+    pub guardian: Option<Signer<'info>>,
+    /// The profile of the entry's author, whose statistics are updated.
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED.as_ref(), journal_entry.author.as_ref()],
+        bump = profile.bump,
+    )]
+    pub profile: Account<'info, UserProfile>,
+    /// The entry's audit log, required only if auditing is enabled for the entry.
+    #[account(mut, seeds = [AUDIT_LOG_SEED.as_ref(), journal_entry.key().as_ref()], bump)]
+    pub audit_log: Option<Account<'info, AuditLog>>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The global statistics shard of the entry's author, updated with the entry count.
+    #[account(
+        mut,
+        seeds = [GLOBAL_STATS_SEED.as_ref(), &[GlobalStats::shard_of(&journal_entry.author)]],
+        bump = global_stats.bump,
+    )]
+    pub global_stats: Account<'info, GlobalStats>,
+}
+
+pub(crate) fn handler(ctx: Context<DeleteEntry>) -> Result<()> {
+    let journal_entry = &mut ctx.accounts.journal_entry;
+    journal_entry.check_not_trashed()?;
+    journal_entry.check_not_sealed()?;
+    journal_entry.check_unlocked()?;
+    journal_entry.check_guardian(ctx.accounts.guardian.as_ref())?;
+    journal_entry.deleted_at = Some(Clock::get()?.unix_timestamp);
+    ctx.accounts.profile.record_entry_trashed();
+    ctx.accounts.global_stats.record_entry_removed();
+    journal_entry.audit(
+        ctx.accounts.audit_log.as_mut(),
+        ctx.accounts.owner.key(),
+        AuditAction::Deleted,
+    )?;
+
+    ctx.accounts
+        .profile
+        .record_activity(ctx.accounts.owner.key(), Clock::get()?.unix_timestamp);
+
+    emit!(EntryTrashed {
+        owner: journal_entry.owner,
+        entry: journal_entry.key(),
+        title: journal_entry.title.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    // Log the deletion message to the Solana runtime, useful for debugging.
+    msg!(
+        "Journal entry titled {} moved to trash",
+        journal_entry.title
+    );
+
+    Ok(())
+}
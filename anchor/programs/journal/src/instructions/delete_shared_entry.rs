@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct DeleteSharedEntry<'info> {
+    /// The member's membership of the shared journal, proving their role.
+    #[account(
+        mut,
+        seeds = [ROLE_SEED.as_ref(), membership.journal.as_ref(), member.key().as_ref()],
+        bump,
+    )]
+    pub membership: Account<'info, JournalMember>,
+    /// CHECK: The author's membership, whose entry count is decremented. It no longer exists if
+    /// the author left the journal, and is not read when the author is the signer, whose
+    /// membership is `membership` itself.
+    #[account(
+        mut,
+        seeds = [ROLE_SEED.as_ref(), membership.journal.as_ref(), journal_entry.author.as_ref()],
+        bump,
+    )]
+    pub author_membership: UncheckedAccount<'info>,
+    /// The shared journal entry to be deleted.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from the entry's recorded author and index.
+    /// - `bump`: The bump stored in the entry.
+    /// - `close`: Closes the account and refunds its rent to the author, who paid for it.
+    #[account(
+        mut,
+        seeds = [
+            ENTRY_SEED.as_ref(),
+            journal_entry.author.as_ref(),
+            &journal_entry.index.to_le_bytes(),
+        ],
+        bump = journal_entry.bump,
+        close = author,
+    )]
+    pub journal_entry: Account<'info, JournalEntryState>,
+    /// The member deleting the entry: an admin, or the writer who authored it.
+    /// This account must sign the transaction to authorize it.
+    pub member: Signer<'info>,
+    /// The entry's author, who receives its rent.
+    #[account(mut, address = journal_entry.author)]
+    pub author: SystemAccount<'info>,
+    /// The profile of the entry's author, whose statistics are updated and whose entry list the
+    /// entry is unlinked from.
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED.as_ref(), journal_entry.author.as_ref()],
+        bump = profile.bump,
+    )]
+    pub profile: Account<'info, UserProfile>,
+    /// The author's entry created before this one, required only if there is one.
+    #[account(mut)]
+    pub prev_entry: Option<Account<'info, JournalEntryState>>,
+    /// The author's entry created after this one, required only if there is one.
+    #[account(mut)]
+    pub next_entry: Option<Account<'info, JournalEntryState>>,
+    /// The entry index page listing the entry, required only if the entry is listed.
+    #[account(
+        mut,
+        seeds = [
+            ENTRY_INDEX_SEED.as_ref(),
+            journal_entry.author.as_ref(),
+            &EntryIndexPage::page_of(journal_entry.index).to_le_bytes(),
+        ],
+        bump = index_page.bump,
+    )]
+    pub index_page: Option<Account<'info, EntryIndexPage>>,
+    /// The global statistics shard of the entry's author, updated with the entry count.
+    #[account(
+        mut,
+        seeds = [GLOBAL_STATS_SEED.as_ref(), &[GlobalStats::shard_of(&journal_entry.author)]],
+        bump = global_stats.bump,
+    )]
+    pub global_stats: Account<'info, GlobalStats>,
+    /// The entry's audit log, required only if auditing is enabled for the entry.
+    #[account(mut, seeds = [AUDIT_LOG_SEED.as_ref(), journal_entry.key().as_ref()], bump)]
+    pub audit_log: Option<Account<'info, AuditLog>>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<DeleteSharedEntry>) -> Result<()> {
+    let journal_entry = &mut ctx.accounts.journal_entry;
+    ctx.accounts.membership.check_can_modify(journal_entry)?;
+    journal_entry.check_not_sealed()?;
+    journal_entry.check_unlocked()?;
+    if journal_entry.author == ctx.accounts.member.key() {
+        ctx.accounts.membership.record_entry_removed(journal_entry);
+    } else {
+        let info = ctx.accounts.author_membership.to_account_info();
+        if info.owner == ctx.program_id && !info.data_is_empty() {
+            let mut author_membership =
+                JournalMember::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+            author_membership.record_entry_removed(journal_entry);
+            author_membership.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+        }
+    }
+    if journal_entry.deleted_at.is_none() {
+        ctx.accounts.profile.record_entry_trashed();
+        ctx.accounts.global_stats.record_entry_removed();
+    }
+    ctx.accounts.profile.unlink_entry(
+        journal_entry,
+        ctx.accounts.prev_entry.as_mut(),
+        ctx.accounts.next_entry.as_mut(),
+    )?;
+    ctx.accounts
+        .profile
+        .unindex_entry(journal_entry, ctx.accounts.index_page.as_mut())?;
+
+    ctx.accounts
+        .profile
+        .record_activity(ctx.accounts.member.key(), Clock::get()?.unix_timestamp);
+
+    journal_entry.audit(
+        ctx.accounts.audit_log.as_mut(),
+        ctx.accounts.member.key(),
+        AuditAction::Deleted,
+    )?;
+
+    emit!(EntryDeleted {
+        owner: journal_entry.owner,
+        entry: journal_entry.key(),
+        title: journal_entry.title.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Shared journal entry titled {} deleted",
+        journal_entry.title
+    );
+
+    Ok(())
+}
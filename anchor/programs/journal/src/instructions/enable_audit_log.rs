@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct EnableAuditLog<'info> {
+    /// The journal entry to audit.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from the entry's recorded author and index.
+    /// - `bump`: The bump stored in the entry.
+    /// - `has_one`: The signer must be the entry's owner, otherwise `NotOwner` is returned.
+    #[account(
+        mut,
+        seeds = [
+            ENTRY_SEED.as_ref(),
+            journal_entry.author.as_ref(),
+            &journal_entry.index.to_le_bytes(),
+        ],
+        bump = journal_entry.bump,
+        has_one = owner @ JournalError::NotOwner,
+    )]
+    pub journal_entry: Account<'info, JournalEntryState>,
+    /// The audit log to be created for the entry.
+    ///
+    /// - `init`: Creates the account; auditing can only be enabled once.
+    /// - `seeds`: A unique identifier for the account, derived from the entry's public key.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init,
+        seeds = [AUDIT_LOG_SEED.as_ref(), journal_entry.key().as_ref()],
+        bump,
+        payer = owner,
+        space = 8 + AuditLog::INIT_SPACE
+    )]
+    pub audit_log: Account<'info, AuditLog>,
+    /// The signer of the transaction.
+    /// This account must sign the transaction to authorize it.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The system program required for account creation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<EnableAuditLog>) -> Result<()> {
+    let journal_entry = &mut ctx.accounts.journal_entry;
+    journal_entry.audited = true;
+
+    let audit_log = &mut ctx.accounts.audit_log;
+    audit_log.entry = journal_entry.key();
+    journal_entry.audit(
+        Some(audit_log),
+        ctx.accounts.owner.key(),
+        AuditAction::Enabled,
+    )?;
+
+    msg!("Audit log enabled");
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(depth: u8)]
+pub struct EnableRevisions<'info> {
+    /// The journal entry whose revisions should be kept.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from the entry's recorded author and index.
+    /// - `bump`: The bump stored in the entry.
+    /// - `has_one`: The signer must be the entry's owner, otherwise `NotOwner` is returned.
+    #[account(
+        mut,
+        seeds = [
+            ENTRY_SEED.as_ref(),
+            journal_entry.author.as_ref(),
+            &journal_entry.index.to_le_bytes(),
+        ],
+        bump = journal_entry.bump,
+        has_one = owner @ JournalError::NotOwner,
+    )]
+    pub journal_entry: Account<'info, JournalEntryState>,
+    /// The revision history to be created for the entry.
+    ///
+    /// - `init`: Creates the account; revisions can only be enabled once.
+    /// - `seeds`: A unique identifier for the account, derived from the entry's public key.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: Room for `depth` revisions of the largest possible message.
+    #[account(
+        init,
+        seeds = [REVISION_SEED.as_ref(), journal_entry.key().as_ref()],
+        bump,
+        payer = owner,
+        space = RevisionHistory::space(depth.min(MAX_REVISION_DEPTH as u8))
+    )]
+    pub revision_history: Account<'info, RevisionHistory>,
+    /// The signer of the transaction.
+    /// This account must sign the transaction to authorize it.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// The entry's audit log, required only if auditing is enabled for the entry.
+    #[account(mut, seeds = [AUDIT_LOG_SEED.as_ref(), journal_entry.key().as_ref()], bump)]
+    pub audit_log: Option<Account<'info, AuditLog>>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The system program required for account creation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<EnableRevisions>, depth: u8) -> Result<()> {
+    require!(
+        (1..=MAX_REVISION_DEPTH as u8).contains(&depth),
+        JournalError::InvalidRevisionDepth
+    );
+
+    let journal_entry = &mut ctx.accounts.journal_entry;
+    journal_entry.revisioned = true;
+
+    let revision_history = &mut ctx.accounts.revision_history;
+    revision_history.entry = journal_entry.key();
+    revision_history.depth = depth;
+
+    journal_entry.audit(
+        ctx.accounts.audit_log.as_mut(),
+        ctx.accounts.owner.key(),
+        AuditAction::RevisionsEnabled,
+    )?;
+
+    msg!("Revisions enabled with depth {}", depth);
+
+    Ok(())
+}
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ExecuteMultisigCreate<'info> {
+    /// The multisig journal the proposal belongs to.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from its creator and identifier.
+    /// - `bump`: The bump stored in the journal.
+    #[account(
+        mut,
+        seeds = [
+            MULTISIG_SEED.as_ref(),
+            multisig.creator.as_ref(),
+            &multisig.id.to_le_bytes(),
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, MultisigJournal>,
+    /// The approved proposal being executed.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `has_one`: The proposal must belong to the journal, otherwise `ProposalMismatch` is returned.
+    /// - `close`: Closes the account and refunds its rent to the proposer.
+    #[account(
+        mut,
+        has_one = multisig @ JournalError::ProposalMismatch,
+        has_one = proposer,
+        close = proposer,
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+    /// The proposer, who receives the proposal's rent.
+    #[account(mut)]
+    pub proposer: SystemAccount<'info>,
+    /// The journal entry created from the proposal.
+    ///
+    /// - `init`: Creates the account at the journal's next entry index.
+    /// - `seeds`: A unique identifier for the account, derived from the multisig journal and the next entry index.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The executing owner pays for the entry.
+    /// - `space`: The size of the proposed title and message.
+    #[account(
+        init,
+        seeds = [
+            ENTRY_SEED.as_ref(),
+            multisig.key().as_ref(),
+            &multisig.entry_count.to_le_bytes(),
+        ],
+        bump,
+        payer = executor,
+        space = JournalEntryState::space(proposal.title.len(), proposal.message.len())
+    )]
+    pub journal_entry: Account<'info, JournalEntryState>,
+    /// The owner executing the proposal.
+    /// This account must sign the transaction to authorize it.
+    #[account(mut)]
+    pub executor: Signer<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The global statistics shard of the entry's author, updated with the entry count.
+    ///
+    /// - `init_if_needed`: Creates the shard with the first entry it counts.
+    /// - `seeds`: A unique identifier for the account, derived from the shard number of the author's public key.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init_if_needed,
+        seeds = [GLOBAL_STATS_SEED.as_ref(), &[GlobalStats::shard_of(&multisig.key())]],
+        bump,
+        payer = executor,
+        space = 8 + GlobalStats::INIT_SPACE
+    )]
+    pub global_stats: Account<'info, GlobalStats>,
+    /// The system program required for account creation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<ExecuteMultisigCreate>) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    let proposal = &ctx.accounts.proposal;
+    multisig.approval_bit(&ctx.accounts.executor.key())?;
+    proposal.check_executable(multisig, MultisigAction::Create, None)?;
+    // The limits may have been lowered since the proposal was made.
+    ctx.accounts
+        .cpi_guard
+        .config
+        .validate_entry(&proposal.title, &proposal.message)?;
+
+    msg!("Multisig Journal Entry Created");
+    msg!("Title: {}", proposal.title);
+
+    let journal_entry = &mut ctx.accounts.journal_entry;
+    journal_entry.owner = multisig.key();
+    journal_entry.author = journal_entry.owner;
+    journal_entry.index = multisig.next_entry_index();
+    journal_entry.bump = ctx.bumps.journal_entry;
+    journal_entry.title = proposal.title.clone();
+    journal_entry.message = proposal.message.clone();
+    journal_entry.status = EntryStatus::Published;
+    journal_entry.touch(Clock::get()?.unix_timestamp);
+    // Multisig journals have no profile, so they are not counted as writers.
+    ctx.accounts
+        .global_stats
+        .record_entry_created(ctx.bumps.global_stats, false);
+
+    emit!(EntryCreated {
+        owner: journal_entry.owner,
+        entry: journal_entry.key(),
+        title: journal_entry.title.clone(),
+        timestamp: journal_entry.created_at,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,279 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ExecuteMultisigDelete<'info> {
+    /// The multisig journal the proposal belongs to.
+    ///
+    /// - `seeds`: A unique identifier for the account, derived from its creator and identifier.
+    /// - `bump`: The bump stored in the journal.
+    #[account(
+        seeds = [
+            MULTISIG_SEED.as_ref(),
+            multisig.creator.as_ref(),
+            &multisig.id.to_le_bytes(),
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, MultisigJournal>,
+    /// The approved proposal being executed.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `has_one`: The proposal must belong to the journal, otherwise `ProposalMismatch` is returned.
+    /// - `close`: Closes the account and refunds its rent to the proposer.
+    #[account(
+        mut,
+        has_one = multisig @ JournalError::ProposalMismatch,
+        has_one = proposer,
+        close = proposer,
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+    /// The proposer, who receives the proposal's rent.
+    #[account(mut)]
+    pub proposer: SystemAccount<'info>,
+    /// The journal entry to be moved to the trash, or purged if it is already there.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from the entry's recorded author and index.
+    /// - `bump`: The bump stored in the entry.
+    /// - `constraint`: The entry must be owned by the multisig journal, otherwise `NotOwner` is returned.
+    #[account(
+        mut,
+        seeds = [
+            ENTRY_SEED.as_ref(),
+            journal_entry.author.as_ref(),
+            &journal_entry.index.to_le_bytes(),
+        ],
+        bump = journal_entry.bump,
+        constraint = journal_entry.owner == multisig.key() @ JournalError::NotOwner,
+    )]
+    pub journal_entry: Account<'info, JournalEntryState>,
+    /// The owner executing the proposal, who receives the entry's rent if it is purged.
+    /// This account must sign the transaction to authorize it.
+    #[account(mut)]
+    pub executor: Signer<'info>,
+    /// The guardian of the entry, required only if the entry is protected.
+    pub guardian: Option<Signer<'info>>,
+    /// The global statistics shard of the entry's author, updated with the entry count.
+    #[account(
+        mut,
+        seeds = [GLOBAL_STATS_SEED.as_ref(), &[GlobalStats::shard_of(&journal_entry.author)]],
+        bump = global_stats.bump,
+    )]
+    pub global_stats: Account<'info, GlobalStats>,
+    /// The entry's audit log, required only if auditing is enabled for the entry.
+    #[account(mut, seeds = [AUDIT_LOG_SEED.as_ref(), journal_entry.key().as_ref()], bump)]
+    pub audit_log: Option<Account<'info, AuditLog>>,
+    /// The entry's revision history, required only if revisions are enabled for the entry.
+    #[account(mut, seeds = [REVISION_SEED.as_ref(), journal_entry.key().as_ref()], bump)]
+    pub revision_history: Option<Account<'info, RevisionHistory>>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+}
+
+pub(crate) fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteMultisigDelete<'info>>,
+) -> Result<()> {
+    ctx.accounts
+        .multisig
+        .approval_bit(&ctx.accounts.executor.key())?;
+    ctx.accounts.proposal.check_executable(
+        &ctx.accounts.multisig,
+        MultisigAction::Delete,
+        Some(ctx.accounts.journal_entry.key()),
+    )?;
+    let journal_entry = &mut ctx.accounts.journal_entry;
+    journal_entry.check_version(ctx.accounts.proposal.expected_version)?;
+    let executor = ctx.accounts.executor.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    // Like single-owner entries, a deleted entry first spends the grace period in the trash.
+    // Multisig journals have no restore, so a second approved deletion purges it.
+    let Some(deleted_at) = journal_entry.deleted_at else {
+        journal_entry.check_not_sealed()?;
+        journal_entry.check_unlocked()?;
+        journal_entry.check_guardian(ctx.accounts.guardian.as_ref())?;
+        journal_entry.deleted_at = Some(now);
+        ctx.accounts.global_stats.record_entry_removed();
+        journal_entry.audit(
+            ctx.accounts.audit_log.as_mut(),
+            executor,
+            AuditAction::Deleted,
+        )?;
+
+        emit!(EntryTrashed {
+            owner: journal_entry.owner,
+            entry: journal_entry.key(),
+            title: journal_entry.title.clone(),
+            timestamp: now,
+        });
+
+        msg!(
+            "Multisig journal entry titled {} moved to trash",
+            journal_entry.title
+        );
+
+        return Ok(());
+    };
+
+    require!(
+        now >= deleted_at.saturating_add(ctx.accounts.cpi_guard.config.trash_grace_period),
+        JournalError::GracePeriodActive
+    );
+    // Each execution consumes its proposal, so unlike `purge_entry` the purge cannot be spread
+    // over several calls and every view record must be closed at once.
+    let key = journal_entry.key();
+    journal_entry.close_companions(key, ctx.remaining_accounts)?;
+    require!(journal_entry.view_count == 0, JournalError::ViewRecordsOpen);
+    let executor = ctx.accounts.executor.to_account_info();
+    journal_entry.close_logs(
+        ctx.accounts.audit_log.as_ref(),
+        ctx.accounts.revision_history.as_ref(),
+        &executor,
+    )?;
+
+    emit!(EntryDeleted {
+        owner: journal_entry.owner,
+        entry: journal_entry.key(),
+        title: journal_entry.title.clone(),
+        timestamp: now,
+    });
+
+    msg!(
+        "Multisig journal entry titled {} purged",
+        journal_entry.title
+    );
+
+    journal_entry.close(executor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    /// A one-owner multisig journal with an entry and an approved proposal deleting it, made
+    /// against version `expected_version`.
+    fn setup(
+        entry_state: impl FnOnce(&mut JournalEntryState),
+        expected_version: u64,
+    ) -> Vec<TestAccount> {
+        let owner = Pubkey::new_unique();
+        let (multisig_key, multisig_bump) = Pubkey::find_program_address(
+            &[MULTISIG_SEED.as_ref(), owner.as_ref(), &0u64.to_le_bytes()],
+            &crate::ID,
+        );
+        let multisig = MultisigJournal {
+            creator: owner,
+            id: 0,
+            owners: vec![owner],
+            threshold: 1,
+            entry_count: 1,
+            proposal_count: 1,
+            bump: multisig_bump,
+        };
+        let (entry_key, mut entry) = entry(multisig_key, 0);
+        entry_state(&mut entry);
+        let proposal = MultisigProposal {
+            multisig: multisig_key,
+            proposer: owner,
+            action: MultisigAction::Delete,
+            entry: Some(entry_key),
+            expected_version,
+            title: String::new(),
+            message: String::new(),
+            approvals: 1,
+            proposed_at: 0,
+            expires_at: i64::MAX,
+        };
+        let (stats_key, stats_bump) = Pubkey::find_program_address(
+            &[
+                GLOBAL_STATS_SEED.as_ref(),
+                &[GlobalStats::shard_of(&multisig_key)],
+            ],
+            &crate::ID,
+        );
+        let stats = GlobalStats {
+            total_entries: 1,
+            active_entries: 1,
+            unique_writers: 0,
+            bump: stats_bump,
+        };
+        let mut proposer = TestAccount::signer(owner);
+        proposer.is_signer = false;
+        vec![
+            TestAccount::program(multisig_key, &multisig).read_only(),
+            TestAccount::program(Pubkey::new_unique(), &proposal),
+            proposer,
+            TestAccount::entry(entry_key, &entry),
+            TestAccount::signer(owner),
+            TestAccount::absent(),
+            TestAccount::program(stats_key, &stats),
+            TestAccount::absent(),
+            TestAccount::absent(),
+            TestAccount::config(&config()),
+            TestAccount::instructions(&crate::ID),
+        ]
+    }
+
+    fn execute(accounts: &mut [TestAccount]) -> Result<()> {
+        let infos = infos(accounts);
+        let (mut accts, bumps) = try_accounts::<ExecuteMultisigDelete>(&infos, &[])?;
+        handler(Context::new(&crate::ID, &mut accts, &[], bumps))?;
+        accts.exit(&crate::ID)
+    }
+
+    #[test]
+    fn deleting_moves_the_entry_to_the_trash() {
+        set_now(100);
+        let mut accounts = setup(|_| {}, 0);
+        execute(&mut accounts).unwrap();
+
+        let entry: JournalEntryState = accounts[3].state();
+        assert_eq!(entry.deleted_at, Some(100));
+    }
+
+    #[test]
+    fn deleting_a_trashed_entry_purges_it_after_the_grace_period() {
+        let trashed = |entry: &mut JournalEntryState| entry.deleted_at = Some(0);
+        set_now(DEFAULT_TRASH_GRACE_PERIOD - 1);
+        let mut early = setup(trashed, 0);
+        assert_eq!(
+            execute(&mut early),
+            Err(JournalError::GracePeriodActive.into())
+        );
+
+        set_now(DEFAULT_TRASH_GRACE_PERIOD);
+        let mut accounts = setup(trashed, 0);
+        let rent = accounts[3].lamports;
+        let executor_lamports = accounts[4].lamports;
+        execute(&mut accounts).unwrap();
+        assert_eq!(accounts[3].lamports, 0);
+        assert!(accounts[3].data().is_empty());
+        assert_eq!(accounts[4].lamports, executor_lamports + rent);
+    }
+
+    #[test]
+    fn sealed_entries_cannot_be_deleted() {
+        set_now(100);
+        let mut accounts = setup(|entry| entry.is_sealed = true, 0);
+        assert_eq!(
+            execute(&mut accounts),
+            Err(JournalError::SealedEntry.into())
+        );
+    }
+
+    #[test]
+    fn entries_written_since_the_proposal_are_not_deleted() {
+        set_now(100);
+        let mut accounts = setup(|entry| entry.version = 2, 1);
+        assert_eq!(
+            execute(&mut accounts),
+            Err(JournalError::StaleVersion.into())
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ExecuteMultisigUpdate<'info> {
+    /// The multisig journal the proposal belongs to.
+    ///
+    /// - `seeds`: A unique identifier for the account, derived from its creator and identifier.
+    /// - `bump`: The bump stored in the journal.
+    #[account(
+        seeds = [
+            MULTISIG_SEED.as_ref(),
+            multisig.creator.as_ref(),
+            &multisig.id.to_le_bytes(),
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, MultisigJournal>,
+    /// The approved proposal being executed.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `has_one`: The proposal must belong to the journal, otherwise `ProposalMismatch` is returned.
+    /// - `close`: Closes the account and refunds its rent to the proposer.
+    #[account(
+        mut,
+        has_one = multisig @ JournalError::ProposalMismatch,
+        has_one = proposer,
+        close = proposer,
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+    /// The proposer, who receives the proposal's rent.
+    #[account(mut)]
+    pub proposer: SystemAccount<'info>,
+    /// The journal entry to be updated.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from the entry's recorded author and index.
+    /// - `bump`: The bump stored in the entry.
+    /// - `constraint`: The entry must be owned by the multisig journal, otherwise `NotOwner` is returned.
+    /// - `realloc`: Reallocates the account to fit the proposed title and message.
+    /// - `realloc::payer`: The account that will pay for the reallocation.
+    /// - `realloc::zero`: Ensures the newly allocated space is zeroed out.
+    #[account(
+        mut,
+        seeds = [
+            ENTRY_SEED.as_ref(),
+            journal_entry.author.as_ref(),
+            &journal_entry.index.to_le_bytes(),
+        ],
+        bump = journal_entry.bump,
+        constraint = journal_entry.owner == multisig.key() @ JournalError::NotOwner,
+        realloc = JournalEntryState::space(proposal.title.len(), proposal.message.len()),
+        realloc::payer = executor,
+        realloc::zero = true,
+    )]
+    pub journal_entry: Account<'info, JournalEntryState>,
+    /// The owner executing the proposal.
+    /// This account must sign the transaction to authorize it.
+    #[account(mut)]
+    pub executor: Signer<'info>,
+    /// The entry's audit log, required only if auditing is enabled for the entry.
+    #[account(mut, seeds = [AUDIT_LOG_SEED.as_ref(), journal_entry.key().as_ref()], bump)]
+    pub audit_log: Option<Account<'info, AuditLog>>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The system program required for account reallocation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<ExecuteMultisigUpdate>) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    let proposal = &ctx.accounts.proposal;
+    multisig.approval_bit(&ctx.accounts.executor.key())?;
+    proposal.check_executable(
+        multisig,
+        MultisigAction::Update,
+        Some(ctx.accounts.journal_entry.key()),
+    )?;
+    ctx.accounts
+        .journal_entry
+        .check_version(proposal.expected_version)?;
+    ctx.accounts.journal_entry.check_editable()?;
+    ctx.accounts
+        .cpi_guard
+        .config
+        .validate_entry(&proposal.title, &proposal.message)?;
+
+    let journal_entry = &mut ctx.accounts.journal_entry;
+    journal_entry.content_type.validate(&proposal.message)?;
+
+    msg!("Multisig Journal Entry Updated");
+    msg!("Title: {}", proposal.title);
+
+    journal_entry.title = proposal.title.clone();
+    journal_entry.message = proposal.message.clone();
+    journal_entry.clear_encryption();
+    journal_entry.touch(Clock::get()?.unix_timestamp);
+
+    journal_entry.audit(
+        ctx.accounts.audit_log.as_mut(),
+        ctx.accounts.executor.key(),
+        AuditAction::Updated,
+    )?;
+
+    emit!(EntryUpdated {
+        owner: journal_entry.owner,
+        entry: journal_entry.key(),
+        title: journal_entry.title.clone(),
+        timestamp: journal_entry.updated_at,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(reader: Pubkey)]
+pub struct GrantAccess<'info> {
+    /// The encrypted journal entry being shared.
+    ///
+    /// - `seeds`: A unique identifier for the account, derived from the entry's recorded author and index.
+    /// - `bump`: The bump stored in the entry.
+    /// - `has_one`: The signer must be the entry's owner, otherwise `NotOwner` is returned.
+    #[account(
+        seeds = [
+            ENTRY_SEED.as_ref(),
+            journal_entry.author.as_ref(),
+            &journal_entry.index.to_le_bytes(),
+        ],
+        bump = journal_entry.bump,
+        has_one = owner @ JournalError::NotOwner,
+    )]
+    pub journal_entry: Account<'info, JournalEntryState>,
+    /// The reader's access to the entry.
+    ///
+    /// - `init_if_needed`: Creates the grant on first use; later calls replace the wrapped key.
+    /// - `seeds`: A unique identifier for the account, derived from the entry and the reader.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init_if_needed,
+        seeds = [ACCESS_GRANT_SEED.as_ref(), journal_entry.key().as_ref(), reader.as_ref()],
+        bump,
+        payer = owner,
+        space = 8 + AccessGrant::INIT_SPACE
+    )]
+    pub access_grant: Account<'info, AccessGrant>,
+    /// The signer of the transaction.
+    /// This account must sign the transaction to authorize it.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// The entry's audit log, required only if auditing is enabled for the entry.
+    #[account(mut, seeds = [AUDIT_LOG_SEED.as_ref(), journal_entry.key().as_ref()], bump)]
+    pub audit_log: Option<Account<'info, AuditLog>>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The system program required for account creation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<GrantAccess>,
+    reader: Pubkey,
+    wrapped_key: Vec<u8>,
+) -> Result<()> {
+    let journal_entry = &ctx.accounts.journal_entry;
+    require!(
+        journal_entry.encryption.is_some(),
+        JournalError::EntryNotEncrypted
+    );
+    require!(
+        wrapped_key.len() <= MAX_WRAPPED_KEY_LENGTH as usize,
+        JournalError::WrappedKeyTooLong
+    );
+
+    ctx.accounts.access_grant.set_inner(AccessGrant {
+        entry: journal_entry.key(),
+        owner: journal_entry.owner,
+        reader,
+        wrapped_key,
+        entry_version: journal_entry.version,
+        granted_at: Clock::get()?.unix_timestamp,
+    });
+
+    journal_entry.audit(
+        ctx.accounts.audit_log.as_mut(),
+        ctx.accounts.owner.key(),
+        AuditAction::AccessGranted,
+    )?;
+
+    msg!(
+        "Journal entry titled {} shared with {}",
+        journal_entry.title,
+        reader
+    );
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    /// The program configuration to be created.
+    ///
+    /// - `init`: Creates the account; the configuration can only be initialized once.
+    /// - `seeds`: A fixed seed, making the configuration a singleton.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init,
+        seeds = [CONFIG_SEED.as_ref()],
+        bump,
+        payer = authority,
+        space = 8 + ProgramConfig::INIT_SPACE
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    /// The program's upgrade authority, who becomes the configuration authority.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// This program, used to locate its program data account.
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::Journal>,
+    /// CHECK: The program data account holding the upgrade authority. Its address is checked
+    /// against `program` above and its contents are deserialized in the handler, because
+    /// `Account<ProgramData>` does not support the `idl-build` feature.
+    pub program_data: UncheckedAccount<'info>,
+    /// The system program required for account creation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<InitializeConfig>) -> Result<()> {
+    let program_data =
+        ProgramData::try_deserialize(&mut &ctx.accounts.program_data.try_borrow_data()?[..])?;
+    require!(
+        program_data.upgrade_authority_address == Some(ctx.accounts.authority.key()),
+        JournalError::NotUpgradeAuthority
+    );
+
+    let config = &mut ctx.accounts.config;
+    config.authority = ctx.accounts.authority.key();
+    config.reject_cpi = false;
+    config.cpi_allowlist = Vec::new();
+    config.treasury = ctx.accounts.authority.key();
+    config.treasury_fee_bps = 0;
+    config.slug_claim_fee = 0;
+    config.max_title_len = MAX_TITLE_LENGTH;
+    config.max_message_len = MAX_MESSAGE_LENGTH;
+    config.trash_grace_period = DEFAULT_TRASH_GRACE_PERIOD;
+
+    msg!("Program config initialized");
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(invitee: Pubkey)]
+pub struct InviteMember<'info> {
+    /// The admin's own membership, proving their role.
+    #[account(
+        seeds = [ROLE_SEED.as_ref(), admin_membership.journal.as_ref(), admin.key().as_ref()],
+        bump,
+    )]
+    pub admin_membership: Account<'info, JournalMember>,
+    /// The invitation to be created.
+    ///
+    /// - `init`: Creates the account; inviting a wallet with a pending invitation fails.
+    /// - `seeds`: A unique identifier for the account, derived from the journal and the invitee's public key.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init,
+        seeds = [
+            INVITATION_SEED.as_ref(),
+            admin_membership.journal.as_ref(),
+            invitee.as_ref(),
+        ],
+        bump,
+        payer = admin,
+        space = 8 + Invitation::INIT_SPACE
+    )]
+    pub invitation: Account<'info, Invitation>,
+    /// The admin signing the transaction.
+    /// This account must sign the transaction to authorize it.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The system program required for account creation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<InviteMember>, invitee: Pubkey, role: MemberRole) -> Result<()> {
+    ctx.accounts
+        .admin_membership
+        .check_role(MemberRole::Admin)?;
+
+    ctx.accounts.invitation.set_inner(Invitation {
+        journal: ctx.accounts.admin_membership.journal,
+        invitee,
+        role,
+        inviter: ctx.accounts.admin.key(),
+        invited_at: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Member {} invited to shared journal", invitee);
+
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct LockEntry<'info> {
+    /// The journal entry to be locked.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from the entry's recorded author and index.
+    /// - `bump`: The bump stored in the entry.
+    /// - `has_one`: The signer must be the entry's owner, otherwise `NotOwner` is returned.
+    #[account(
+        mut,
+        seeds = [
+            ENTRY_SEED.as_ref(),
+            journal_entry.author.as_ref(),
+            &journal_entry.index.to_le_bytes(),
+        ],
+        bump = journal_entry.bump,
+        has_one = owner @ JournalError::NotOwner,
+    )]
+    pub journal_entry: Account<'info, JournalEntryState>,
+    /// The signer of the transaction.
+    /// This account must sign the transaction to authorize it.
+    pub owner: Signer<'info>,
+    /// The entry's audit log, required only if auditing is enabled for the entry.
+    #[account(mut, seeds = [AUDIT_LOG_SEED.as_ref(), journal_entry.key().as_ref()], bump)]
+    pub audit_log: Option<Account<'info, AuditLog>>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<LockEntry>, unlock_at: i64) -> Result<()> {
+    let journal_entry = &mut ctx.accounts.journal_entry;
+    journal_entry.check_not_trashed()?;
+    require!(
+        unlock_at > Clock::get()?.unix_timestamp,
+        JournalError::InvalidUnlockTime
+    );
+    if let Some(current) = journal_entry.unlock_at {
+        require!(unlock_at >= current, JournalError::LockShortened);
+    }
+    journal_entry.unlock_at = Some(unlock_at);
+
+    journal_entry.audit(
+        ctx.accounts.audit_log.as_mut(),
+        ctx.accounts.owner.key(),
+        AuditAction::Locked,
+    )?;
+
+    msg!("Journal entry locked until {}", unlock_at);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    fn try_lock_entry(entry_owner: Pubkey, signer: Pubkey, config: &ProgramConfig) -> Result<()> {
+        let (key, entry) = entry(entry_owner, 0);
+        let caller = Pubkey::new_unique();
+        let mut accounts = [
+            TestAccount::program(key, &entry),
+            TestAccount::signer(signer),
+            TestAccount::absent(),
+            TestAccount::config(config),
+            TestAccount::instructions(&caller),
+        ];
+        try_accounts::<LockEntry>(&infos(&mut accounts), &[]).map(|_| ())
+    }
+
+    /// Locks a fresh entry at 100 until `unlock_at`, returning the entry as left by the
+    /// instruction.
+    fn lock(unlock_at: i64) -> Result<JournalEntryState> {
+        set_now(100);
+        let owner = Pubkey::new_unique();
+        let (key, entry) = entry(owner, 0);
+        let mut accounts = [
+            TestAccount::entry(key, &entry),
+            TestAccount::signer(owner),
+            TestAccount::absent(),
+            TestAccount::config(&config()),
+            TestAccount::instructions(&crate::ID),
+        ];
+        {
+            let infos = infos(&mut accounts);
+            let (mut accts, bumps) = try_accounts::<LockEntry>(&infos, &[])?;
+            handler(Context::new(&crate::ID, &mut accts, &[], bumps), unlock_at)?;
+            accts.exit(&crate::ID)?;
+        }
+        Ok(accounts[0].state())
+    }
+
+    #[test]
+    fn entries_lock_until_a_future_time() {
+        assert_eq!(lock(101).unwrap().unlock_at, Some(101));
+    }
+
+    #[test]
+    fn unlock_times_not_in_the_future_are_rejected() {
+        for unlock_at in [0, 100] {
+            assert_eq!(
+                lock(unlock_at).err(),
+                Some(JournalError::InvalidUnlockTime.into())
+            );
+        }
+    }
+
+    #[test]
+    fn owner_can_lock_the_entry() {
+        let owner = Pubkey::new_unique();
+        assert!(try_lock_entry(owner, owner, &config()).is_ok());
+    }
+
+    #[test]
+    fn another_signer_is_not_the_owner() {
+        let result = try_lock_entry(Pubkey::new_unique(), Pubkey::new_unique(), &config());
+        assert_eq!(result, Err(JournalError::NotOwner.into()));
+    }
+
+    #[test]
+    fn cpi_is_rejected_when_the_policy_says_so() {
+        let owner = Pubkey::new_unique();
+        let config = ProgramConfig {
+            reject_cpi: true,
+            ..config()
+        };
+        set_stack_height(1);
+        assert!(try_lock_entry(owner, owner, &config).is_ok());
+        set_stack_height(2);
+        assert_eq!(
+            try_lock_entry(owner, owner, &config),
+            Err(JournalError::CpiNotAllowed.into())
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::JournalError;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct MigrateJournal<'info> {
+    /// The current owner of the entries.
+    /// This account must sign the transaction to authorize it.
+    ///
+    /// The entries to migrate are passed as writable remaining accounts.
+    pub owner: Signer<'info>,
+    /// The wallet receiving the entries.
+    /// It must also sign, so entries cannot be sent to a key nobody controls.
+    pub new_owner: Signer<'info>,
+    /// The guardian of the protected entries in the batch, required only if there are any.
+    /// Entries with different guardians are migrated in separate batches.
+    pub guardian: Option<Signer<'info>>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+}
+
+pub(crate) fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, MigrateJournal<'info>>,
+) -> Result<()> {
+    let owner = ctx.accounts.owner.key();
+    let new_owner = ctx.accounts.new_owner.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    for info in ctx.remaining_accounts {
+        require!(info.is_writable, ErrorCode::ConstraintMut);
+        let mut journal_entry = Account::<JournalEntryState>::try_from(info)?;
+        require_keys_eq!(journal_entry.owner, owner, JournalError::NotOwner);
+        // The batch has no room for audit logs, so audited entries go through `accept_transfer`.
+        require!(!journal_entry.audited, JournalError::AuditLogRequired);
+        journal_entry.check_guardian(ctx.accounts.guardian.as_ref())?;
+        journal_entry.owner = new_owner;
+        journal_entry.pending_owner = None;
+        journal_entry.exit(ctx.program_id)?;
+
+        emit!(EntryTransferred {
+            from: owner,
+            to: new_owner,
+            entry: journal_entry.key(),
+            timestamp: now,
+        });
+    }
+
+    msg!(
+        "Migrated {} journal entries from {} to {}",
+        ctx.remaining_accounts.len(),
+        owner,
+        new_owner
+    );
+
+    Ok(())
+}
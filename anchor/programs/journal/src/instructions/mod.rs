@@ -0,0 +1,173 @@
+pub mod accept_invitation;
+pub mod accept_transfer;
+pub mod add_attachment;
+pub mod append_journal_entry;
+pub mod approve_entry;
+pub mod approve_multisig_action;
+pub mod attest_entry;
+pub mod buy_prompt_pack;
+pub mod cancel_invitation;
+pub mod cancel_multisig_proposal;
+pub mod claim_inherited_journal;
+pub mod claim_slug;
+pub mod close_journal;
+pub mod commit_entry;
+pub mod create_encrypted_entry;
+pub mod create_entry_with_permit;
+pub mod create_journal;
+pub mod create_journal_entry;
+pub mod create_multisig_journal;
+pub mod create_private_entry;
+pub mod create_shared_entry;
+pub mod create_shared_journal;
+pub mod delegate_create_entry;
+pub mod delegate_update_message;
+pub mod delete_journal_entry;
+pub mod delete_shared_entry;
+pub mod enable_audit_log;
+pub mod enable_revisions;
+pub mod execute_multisig_create;
+pub mod execute_multisig_delete;
+pub mod execute_multisig_update;
+pub mod grant_access;
+pub mod heartbeat;
+pub mod initialize_config;
+pub mod initiate_transfer;
+pub mod invite_member;
+pub mod lock_entry;
+pub mod migrate_journal;
+pub mod move_entry;
+pub mod notarize_document;
+pub mod propose_entry;
+pub mod propose_multisig_action;
+pub mod prune_revisions;
+pub mod publish_entry;
+pub mod publish_prompt_pack;
+pub mod purge_entry;
+pub mod record_view;
+pub mod reject_entry;
+pub mod release_entries;
+pub mod release_slug;
+pub mod remove_attachment;
+pub mod remove_member;
+pub mod rename_journal_entry;
+pub mod restore_entry;
+pub mod restore_revision;
+pub mod reveal_entry;
+pub mod revoke_access;
+pub mod revoke_attestation;
+pub mod revoke_write_delegate;
+pub mod rotate_encryption_key;
+pub mod seal_journal_entry;
+pub mod set_archived;
+pub mod set_beneficiary;
+pub mod set_category;
+pub mod set_content_type;
+pub mod set_cover;
+pub mod set_edit_window;
+pub mod set_entry_guardian;
+pub mod set_language;
+pub mod set_location;
+pub mod set_member_role;
+pub mod set_mood;
+pub mod set_release_delay;
+pub mod set_tags;
+pub mod set_toc_order;
+pub mod set_witness;
+pub mod set_write_delegate;
+pub mod update_config;
+pub mod update_encrypted_message;
+pub mod update_entry_limits;
+pub mod update_journal_entry;
+pub mod update_message;
+pub mod update_shared_entry;
+pub mod update_trash_grace_period;
+pub mod update_treasury;
+pub mod witness_entry;
+
+pub use accept_invitation::*;
+pub use accept_transfer::*;
+pub use add_attachment::*;
+pub use append_journal_entry::*;
+pub use approve_entry::*;
+pub use approve_multisig_action::*;
+pub use attest_entry::*;
+pub use buy_prompt_pack::*;
+pub use cancel_invitation::*;
+pub use cancel_multisig_proposal::*;
+pub use claim_inherited_journal::*;
+pub use claim_slug::*;
+pub use close_journal::*;
+pub use commit_entry::*;
+pub use create_encrypted_entry::*;
+pub use create_entry_with_permit::*;
+pub use create_journal::*;
+pub use create_journal_entry::*;
+pub use create_multisig_journal::*;
+pub use create_private_entry::*;
+pub use create_shared_entry::*;
+pub use create_shared_journal::*;
+pub use delegate_create_entry::*;
+pub use delegate_update_message::*;
+pub use delete_journal_entry::*;
+pub use delete_shared_entry::*;
+pub use enable_audit_log::*;
+pub use enable_revisions::*;
+pub use execute_multisig_create::*;
+pub use execute_multisig_delete::*;
+pub use execute_multisig_update::*;
+pub use grant_access::*;
+pub use heartbeat::*;
+pub use initialize_config::*;
+pub use initiate_transfer::*;
+pub use invite_member::*;
+pub use lock_entry::*;
+pub use migrate_journal::*;
+pub use move_entry::*;
+pub use notarize_document::*;
+pub use propose_entry::*;
+pub use propose_multisig_action::*;
+pub use prune_revisions::*;
+pub use publish_entry::*;
+pub use publish_prompt_pack::*;
+pub use purge_entry::*;
+pub use record_view::*;
+pub use reject_entry::*;
+pub use release_entries::*;
+pub use release_slug::*;
+pub use remove_attachment::*;
+pub use remove_member::*;
+pub use rename_journal_entry::*;
+pub use restore_entry::*;
+pub use restore_revision::*;
+pub use reveal_entry::*;
+pub use revoke_access::*;
+pub use revoke_attestation::*;
+pub use revoke_write_delegate::*;
+pub use rotate_encryption_key::*;
+pub use seal_journal_entry::*;
+pub use set_archived::*;
+pub use set_beneficiary::*;
+pub use set_category::*;
+pub use set_content_type::*;
+pub use set_cover::*;
+pub use set_edit_window::*;
+pub use set_entry_guardian::*;
+pub use set_language::*;
+pub use set_location::*;
+pub use set_member_role::*;
+pub use set_mood::*;
+pub use set_release_delay::*;
+pub use set_tags::*;
+pub use set_toc_order::*;
+pub use set_witness::*;
+pub use set_write_delegate::*;
+pub use update_config::*;
+pub use update_encrypted_message::*;
+pub use update_entry_limits::*;
+pub use update_journal_entry::*;
+pub use update_message::*;
+pub use update_shared_entry::*;
+pub use update_trash_grace_period::*;
+pub use update_treasury::*;
+pub use witness_entry::*;
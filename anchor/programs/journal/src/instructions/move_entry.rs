@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct MoveEntry<'info> {
+    /// The journal entry being moved.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from the entry's recorded author and index.
+    /// - `bump`: The bump stored in the entry.
+    /// - `has_one`: The signer must be the entry's owner, otherwise `NotOwner` is returned.
+    #[account(
+        mut,
+        seeds = [
+            ENTRY_SEED.as_ref(),
+            journal_entry.author.as_ref(),
+            &journal_entry.index.to_le_bytes(),
+        ],
+        bump = journal_entry.bump,
+        has_one = owner @ JournalError::NotOwner,
+    )]
+    pub journal_entry: Account<'info, JournalEntryState>,
+    /// The signer of the transaction.
+    /// This account must sign the transaction to authorize it.
+    pub owner: Signer<'info>,
+    /// The journal the entry is filed in, required only if it is filed in a named journal. It
+    /// may belong to a previous owner of the entry.
+    #[account(mut)]
+    pub from_journal: Option<Account<'info, Journal>>,
+    /// The journal to file the entry in, or `None` to move it to the owner's default journal.
+    #[account(mut, has_one = owner @ JournalError::NotOwner)]
+    pub to_journal: Option<Account<'info, Journal>>,
+    /// The entry's audit log, required only if auditing is enabled for the entry.
+    #[account(mut, seeds = [AUDIT_LOG_SEED.as_ref(), journal_entry.key().as_ref()], bump)]
+    pub audit_log: Option<Account<'info, AuditLog>>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<MoveEntry>) -> Result<()> {
+    let journal_entry = &mut ctx.accounts.journal_entry;
+    let to_journal = ctx.accounts.to_journal.as_mut();
+    // Passing the same journal twice would count the entry in it twice.
+    require!(
+        journal_entry.journal != to_journal.as_ref().map(|journal| journal.key()),
+        JournalError::AlreadyInJournal
+    );
+    journal_entry.leave_journal(ctx.accounts.from_journal.as_mut())?;
+    if let Some(to_journal) = to_journal {
+        journal_entry.join_journal(to_journal);
+    }
+
+    journal_entry.audit(
+        ctx.accounts.audit_log.as_mut(),
+        ctx.accounts.owner.key(),
+        AuditAction::Moved,
+    )?;
+
+    msg!("Journal entry titled {} moved", journal_entry.title);
+
+    Ok(())
+}
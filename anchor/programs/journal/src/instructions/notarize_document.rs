@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(hash: [u8; 32])]
+pub struct NotarizeDocument<'info> {
+    /// The notarization record to be created for the document.
+    ///
+    /// - `init`: Creates the account; notarizing the same hash twice fails.
+    /// - `seeds`: A unique identifier for the account, derived from the document hash and owner's public key.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init,
+        seeds = [NOTARIZATION_SEED.as_ref(), hash.as_ref(), owner.key().as_ref()],
+        bump,
+        payer = owner,
+        space = 8 + DocumentNotarization::INIT_SPACE
+    )]
+    pub notarization: Account<'info, DocumentNotarization>,
+    /// The signer of the transaction.
+    /// This account must sign the transaction to authorize it.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The system program required for account creation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<NotarizeDocument>,
+    hash: [u8; 32],
+    algorithm: HashAlgorithm,
+    label: String,
+) -> Result<()> {
+    require!(
+        label.len() <= MAX_LABEL_LENGTH as usize,
+        JournalError::LabelTooLong
+    );
+
+    let notarization = &mut ctx.accounts.notarization;
+    notarization.owner = ctx.accounts.owner.key();
+    notarization.hash = hash;
+    notarization.algorithm = algorithm;
+    notarization.label = label;
+    notarization.notarized_at = Clock::get()?.unix_timestamp;
+
+    msg!("Document notarized at {}", notarization.notarized_at);
+
+    Ok(())
+}
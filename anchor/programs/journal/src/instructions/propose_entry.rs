@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(title: String)]
+pub struct ProposeEntry<'info> {
+    /// The pending entry to be created.
+    ///
+    /// - `init`: Creates the account; proposing the same title twice to the same owner fails.
+    /// - `seeds`: A unique identifier for the account, derived from the owner, the proposer and the title hash.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init,
+        seeds = [
+            PENDING_ENTRY_SEED.as_ref(),
+            owner.key().as_ref(),
+            proposer.key().as_ref(),
+            PendingEntry::title_seed(&title).as_ref(),
+        ],
+        bump,
+        payer = proposer,
+        space = 8 + PendingEntry::INIT_SPACE
+    )]
+    pub pending_entry: Account<'info, PendingEntry>,
+    /// The owner of the journal the entry is proposed for.
+    pub owner: SystemAccount<'info>,
+    /// The guest proposing the entry.
+    /// This account must sign the transaction to authorize it.
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The system program required for account creation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<ProposeEntry>, title: String, message: String) -> Result<()> {
+    ctx.accounts
+        .cpi_guard
+        .config
+        .validate_entry(&title, &message)?;
+
+    msg!("Journal Entry Proposed");
+    msg!("Title: {}", title);
+
+    let pending_entry = &mut ctx.accounts.pending_entry;
+    pending_entry.proposer = ctx.accounts.proposer.key();
+    pending_entry.owner = ctx.accounts.owner.key();
+    pending_entry.title = title;
+    pending_entry.message = message;
+    pending_entry.proposed_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
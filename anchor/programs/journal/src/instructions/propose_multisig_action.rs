@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ProposeMultisigAction<'info> {
+    /// The multisig journal the proposal is for.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from its creator and identifier.
+    /// - `bump`: The bump stored in the journal.
+    #[account(
+        mut,
+        seeds = [
+            MULTISIG_SEED.as_ref(),
+            multisig.creator.as_ref(),
+            &multisig.id.to_le_bytes(),
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, MultisigJournal>,
+    /// The proposal to be created.
+    ///
+    /// - `init`: Creates the account at the journal's next proposal index.
+    /// - `seeds`: A unique identifier for the account, derived from the journal and the proposal index.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The proposer pays for the proposal and is refunded when it is executed or cancelled.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init,
+        seeds = [
+            MULTISIG_PROPOSAL_SEED.as_ref(),
+            multisig.key().as_ref(),
+            &multisig.proposal_count.to_le_bytes(),
+        ],
+        bump,
+        payer = proposer,
+        space = 8 + MultisigProposal::INIT_SPACE
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+    /// The owner making the proposal, whose approval is counted immediately.
+    /// This account must sign the transaction to authorize it.
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The system program required for account creation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<ProposeMultisigAction>,
+    action: MultisigAction,
+    entry: Option<Pubkey>,
+    expected_version: u64,
+    title: String,
+    message: String,
+) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    let approvals = multisig.approval_bit(&ctx.accounts.proposer.key())?;
+    match action {
+        MultisigAction::Create => {
+            require!(
+                entry.is_none() && expected_version == 0,
+                JournalError::ProposalMismatch
+            );
+            ctx.accounts
+                .cpi_guard
+                .config
+                .validate_entry(&title, &message)?;
+        }
+        MultisigAction::Update => {
+            require!(entry.is_some(), JournalError::ProposalMismatch);
+            ctx.accounts
+                .cpi_guard
+                .config
+                .validate_entry(&title, &message)?;
+        }
+        MultisigAction::Delete => {
+            require!(
+                entry.is_some() && title.is_empty() && message.is_empty(),
+                JournalError::ProposalMismatch
+            );
+        }
+    }
+    multisig.proposal_count += 1;
+    let now = Clock::get()?.unix_timestamp;
+
+    ctx.accounts.proposal.set_inner(MultisigProposal {
+        multisig: multisig.key(),
+        proposer: ctx.accounts.proposer.key(),
+        action,
+        entry,
+        expected_version,
+        title,
+        message,
+        approvals,
+        proposed_at: now,
+        expires_at: now.saturating_add(MULTISIG_PROPOSAL_LIFETIME),
+    });
+
+    msg!("Multisig proposal {} created", multisig.proposal_count - 1);
+
+    Ok(())
+}
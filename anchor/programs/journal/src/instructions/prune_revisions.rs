@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::JournalError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct PruneRevisions<'info> {
+    /// The owner of the snapshots, who receives their rent.
+    /// This account must sign the transaction to authorize it.
+    ///
+    /// The snapshots to prune are passed as remaining accounts.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+}
+
+pub(crate) fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, PruneRevisions<'info>>,
+) -> Result<()> {
+    let owner = ctx.accounts.owner.to_account_info();
+    for info in ctx.remaining_accounts {
+        require!(info.is_writable, ErrorCode::ConstraintMut);
+        let snapshot = Account::<JournalEntryRevision>::try_from(info)?;
+        require_keys_eq!(snapshot.owner, owner.key(), JournalError::NotOwner);
+        snapshot.close(owner.clone())?;
+    }
+
+    msg!(
+        "Pruned {} journal entry snapshots",
+        ctx.remaining_accounts.len()
+    );
+
+    Ok(())
+}
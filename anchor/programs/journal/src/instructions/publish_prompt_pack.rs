@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct PublishPromptPack<'info> {
+    /// The prompt pack to be created.
+    ///
+    /// - `init`: Creates the account; publishing the same name twice fails.
+    /// - `seeds`: A unique identifier for the account, derived from the creator's public key and the pack name.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init,
+        seeds = [PROMPT_PACK_SEED.as_ref(), creator.key().as_ref(), name.as_bytes()],
+        bump,
+        payer = creator,
+        space = 8 + PromptPack::INIT_SPACE
+    )]
+    pub prompt_pack: Account<'info, PromptPack>,
+    /// The creator publishing the pack.
+    /// This account must sign the transaction to authorize it.
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The system program required for account creation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<PublishPromptPack>,
+    name: String,
+    price: u64,
+    prompts: Vec<String>,
+) -> Result<()> {
+    require!(
+        name.len() <= MAX_PACK_NAME_LENGTH as usize,
+        JournalError::PackNameTooLong
+    );
+    require!(
+        !prompts.is_empty() && prompts.len() <= MAX_PROMPTS_PER_PACK as usize,
+        JournalError::InvalidPromptCount
+    );
+    for prompt in &prompts {
+        require!(
+            prompt.len() <= MAX_PROMPT_LENGTH as usize,
+            JournalError::PromptTooLong
+        );
+    }
+
+    msg!("Prompt pack published: {}", name);
+
+    let prompt_pack = &mut ctx.accounts.prompt_pack;
+    prompt_pack.creator = ctx.accounts.creator.key();
+    prompt_pack.name = name;
+    prompt_pack.price = price;
+    prompt_pack.prompts = prompts;
+
+    Ok(())
+}
@@ -0,0 +1,243 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct PurgeEntry<'info> {
+    /// The trashed journal entry to be closed.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from the entry's recorded author and index.
+    /// - `bump`: The bump stored in the entry.
+    /// - `has_one`: The signer must be the entry's owner, otherwise `NotOwner` is returned.
+    #[account(
+        mut,
+        seeds = [
+            ENTRY_SEED.as_ref(),
+            journal_entry.author.as_ref(),
+            &journal_entry.index.to_le_bytes(),
+        ],
+        bump = journal_entry.bump,
+        has_one = owner @ JournalError::NotOwner,
+    )]
+    pub journal_entry: Account<'info, JournalEntryState>,
+    /// The signer of the transaction.
+    /// This account must sign the transaction to authorize it.
+    pub owner: Signer<'info>,
+    /// The system account that receives the lamports of the closed entry, audit log and revision
+    /// history. Pass the owner to keep the refund, or a sponsor that paid the entry's rent.
+    #[account(mut)]
+    pub rent_recipient: SystemAccount<'info>,
+    /// The entry's journal, required only if the entry is filed in a named journal.
+    #[account(mut)]
+    pub journal: Option<Account<'info, Journal>>,
+    /// The profile of the entry's author, whose entry list the entry is unlinked from.
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED.as_ref(), journal_entry.author.as_ref()],
+        bump = profile.bump,
+    )]
+    pub profile: Account<'info, UserProfile>,
+    /// The author's entry created before this one, required only if there is one.
+    #[account(mut)]
+    pub prev_entry: Option<Account<'info, JournalEntryState>>,
+    /// The author's entry created after this one, required only if there is one.
+    #[account(mut)]
+    pub next_entry: Option<Account<'info, JournalEntryState>>,
+    /// The entry index page listing the entry, required only if the entry is listed.
+    #[account(
+        mut,
+        seeds = [
+            ENTRY_INDEX_SEED.as_ref(),
+            journal_entry.author.as_ref(),
+            &EntryIndexPage::page_of(journal_entry.index).to_le_bytes(),
+        ],
+        bump = index_page.bump,
+    )]
+    pub index_page: Option<Account<'info, EntryIndexPage>>,
+    /// The entry's audit log, required only if auditing is enabled for the entry.
+    #[account(mut, seeds = [AUDIT_LOG_SEED.as_ref(), journal_entry.key().as_ref()], bump)]
+    pub audit_log: Option<Account<'info, AuditLog>>,
+    /// The entry's revision history, required only if revisions are enabled for the entry.
+    #[account(mut, seeds = [REVISION_SEED.as_ref(), journal_entry.key().as_ref()], bump)]
+    pub revision_history: Option<Account<'info, RevisionHistory>>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+}
+
+pub(crate) fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, PurgeEntry<'info>>) -> Result<()> {
+    let journal_entry = &mut ctx.accounts.journal_entry;
+    let deleted_at = journal_entry
+        .deleted_at
+        .ok_or(JournalError::EntryNotTrashed)?;
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now >= deleted_at.saturating_add(ctx.accounts.cpi_guard.config.trash_grace_period),
+        JournalError::GracePeriodActive
+    );
+
+    ctx.accounts
+        .profile
+        .record_activity(ctx.accounts.owner.key(), now);
+
+    // Every view record costs its viewer rent that nothing else refunds, so the entry stays in
+    // the trash until all of them are closed. Entries viewed by more wallets than fit in one
+    // transaction are purged over several, each closing the view records it is passed.
+    let key = journal_entry.key();
+    journal_entry.close_companions(key, ctx.remaining_accounts)?;
+    if journal_entry.view_count > 0 {
+        msg!(
+            "Journal entry titled {} has {} view records left to close",
+            journal_entry.title,
+            journal_entry.view_count
+        );
+        return Ok(());
+    }
+
+    journal_entry.leave_journal(ctx.accounts.journal.as_mut())?;
+    ctx.accounts.profile.unlink_entry(
+        journal_entry,
+        ctx.accounts.prev_entry.as_mut(),
+        ctx.accounts.next_entry.as_mut(),
+    )?;
+    ctx.accounts
+        .profile
+        .unindex_entry(journal_entry, ctx.accounts.index_page.as_mut())?;
+
+    let rent_recipient = ctx.accounts.rent_recipient.to_account_info();
+    journal_entry.close_logs(
+        ctx.accounts.audit_log.as_ref(),
+        ctx.accounts.revision_history.as_ref(),
+        &rent_recipient,
+    )?;
+
+    emit!(EntryDeleted {
+        owner: journal_entry.owner,
+        entry: journal_entry.key(),
+        title: journal_entry.title.clone(),
+        timestamp: now,
+    });
+
+    msg!("Journal entry titled {} purged", journal_entry.title);
+
+    journal_entry.close(rent_recipient)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    /// An audited entry trashed at 0 and seen by `views` wallets, with its audit log, followed
+    /// by one view record and its viewer. The record is of another entry if `foreign_view` is set.
+    fn setup(views: u64, foreign_view: bool) -> Vec<TestAccount> {
+        set_now(DEFAULT_TRASH_GRACE_PERIOD);
+        let owner = Pubkey::new_unique();
+        let (entry_key, mut entry) = entry(owner, 0);
+        entry.deleted_at = Some(0);
+        entry.audited = true;
+        entry.view_count = views;
+        let (profile_key, mut profile) = profile(owner);
+        profile.head_entry = Some(entry_key);
+        profile.tail_entry = Some(entry_key);
+        let (audit_key, _) = Pubkey::find_program_address(
+            &[AUDIT_LOG_SEED.as_ref(), entry_key.as_ref()],
+            &crate::ID,
+        );
+        let audit_log = AuditLog {
+            entry: entry_key,
+            total_records: 0,
+            next_index: 0,
+            records: Vec::new(),
+        };
+        let mut recipient = TestAccount::signer(Pubkey::new_unique());
+        recipient.is_signer = false;
+        let mut accounts = vec![
+            TestAccount::entry(entry_key, &entry),
+            TestAccount::signer(owner),
+            recipient,
+            TestAccount::absent(),
+            TestAccount::program(profile_key, &profile),
+            TestAccount::absent(),
+            TestAccount::absent(),
+            TestAccount::absent(),
+            TestAccount::program(audit_key, &audit_log),
+            TestAccount::absent(),
+            TestAccount::config(&config()),
+            TestAccount::instructions(&crate::ID),
+        ];
+        let viewer = Pubkey::new_unique();
+        let view = EntryView {
+            viewer,
+            entry: if foreign_view {
+                Pubkey::new_unique()
+            } else {
+                entry_key
+            },
+            viewed_at: 0,
+        };
+        let mut viewer = TestAccount::signer(viewer);
+        viewer.is_signer = false;
+        accounts.push(TestAccount::program(Pubkey::new_unique(), &view));
+        accounts.push(viewer);
+        accounts
+    }
+
+    fn purge(accounts: &mut [TestAccount]) -> Result<()> {
+        let infos = infos(accounts);
+        let (named, remaining) = infos.split_at(12);
+        let (mut accts, bumps) = try_accounts::<PurgeEntry>(named, &[])?;
+        handler(Context::new(&crate::ID, &mut accts, remaining, bumps))?;
+        accts.exit(&crate::ID)
+    }
+
+    #[test]
+    fn purging_closes_the_audit_log_and_view_records() {
+        let mut accounts = setup(1, false);
+        let rent = accounts[0].lamports + accounts[8].lamports;
+        let recipient_lamports = accounts[2].lamports;
+        let view_rent = accounts[12].lamports;
+        let viewer_lamports = accounts[13].lamports;
+        purge(&mut accounts).unwrap();
+
+        assert!(accounts[0].data().is_empty());
+        assert!(accounts[8].data().is_empty());
+        assert!(accounts[12].data().is_empty());
+        assert_eq!(accounts[2].lamports, recipient_lamports + rent);
+        assert_eq!(accounts[13].lamports, viewer_lamports + view_rent);
+    }
+
+    #[test]
+    fn entries_with_view_records_left_stay_in_the_trash() {
+        let mut accounts = setup(2, false);
+        purge(&mut accounts).unwrap();
+
+        assert!(accounts[12].data().is_empty());
+        let entry: JournalEntryState = accounts[0].state();
+        assert_eq!(entry.view_count, 1);
+        let audit_log: AuditLog = accounts[8].state();
+        assert_eq!(audit_log.total_records, 0);
+    }
+
+    #[test]
+    fn audited_entries_need_their_audit_log() {
+        let mut accounts = setup(1, false);
+        accounts[8] = TestAccount::absent();
+        assert_eq!(
+            purge(&mut accounts),
+            Err(JournalError::AuditLogRequired.into())
+        );
+    }
+
+    #[test]
+    fn view_records_of_other_entries_are_rejected() {
+        let mut accounts = setup(1, true);
+        assert_eq!(
+            purge(&mut accounts),
+            Err(JournalError::CompanionAccountMismatch.into())
+        );
+    }
+}
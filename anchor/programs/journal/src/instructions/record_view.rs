@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct RecordView<'info> {
+    /// The journal entry being viewed.
+    ///
+    /// - `mut`: The account is mutable, meaning its view count can be incremented.
+    #[account(mut)]
+    pub journal_entry: Account<'info, JournalEntryState>,
+    /// The view record for this viewer and entry.
+    ///
+    /// - `init_if_needed`: Initializes the account on the first view only.
+    /// - `seeds`: A unique identifier for the account, derived from the entry and viewer's public keys.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The viewer pays for the view record, which makes fake views cost rent.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init_if_needed,
+        seeds = [VIEW_SEED.as_ref(), journal_entry.key().as_ref(), viewer.key().as_ref()],
+        bump,
+        payer = viewer,
+        space = 8 + EntryView::INIT_SPACE
+    )]
+    pub entry_view: Account<'info, EntryView>,
+    /// The wallet viewing the entry.
+    /// This account must sign the transaction to authorize it.
+    #[account(mut)]
+    pub viewer: Signer<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The system program required for account creation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<RecordView>) -> Result<()> {
+    // View records must all be closed before the entry is purged, so none are added to it once
+    // it is in the trash.
+    ctx.accounts.journal_entry.check_not_trashed()?;
+    ctx.accounts.journal_entry.check_published()?;
+
+    let entry_view = &mut ctx.accounts.entry_view;
+    // A freshly initialized view record has no viewer yet; anything else is a repeat view.
+    if entry_view.viewer != Pubkey::default() {
+        msg!("View already recorded");
+        return Ok(());
+    }
+
+    entry_view.viewer = ctx.accounts.viewer.key();
+    entry_view.entry = ctx.accounts.journal_entry.key();
+    entry_view.viewed_at = Clock::get()?.unix_timestamp;
+
+    let journal_entry = &mut ctx.accounts.journal_entry;
+    journal_entry.view_count = journal_entry.view_count.saturating_add(1);
+    msg!("View recorded, total views: {}", journal_entry.view_count);
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::JournalError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct RejectEntry<'info> {
+    /// The pending entry being rejected.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `has_one`: The signer must be the journal owner, otherwise `NotOwner` is returned.
+    /// - `close`: Closes the account and refunds its rent to the proposer.
+    #[account(
+        mut,
+        has_one = owner @ JournalError::NotOwner,
+        has_one = proposer,
+        close = proposer,
+    )]
+    pub pending_entry: Account<'info, PendingEntry>,
+    /// The journal owner signing the transaction.
+    /// This account must sign the transaction to authorize it.
+    pub owner: Signer<'info>,
+    /// The proposer, who receives the pending entry's rent.
+    #[account(mut)]
+    pub proposer: SystemAccount<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<RejectEntry>) -> Result<()> {
+    msg!("Journal Entry Rejected");
+    msg!("Title: {}", ctx.accounts.pending_entry.title);
+
+    Ok(())
+}
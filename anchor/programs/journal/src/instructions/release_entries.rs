@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ReleaseEntries<'info> {
+    /// The profile of the inactive owner.
+    ///
+    /// - `seeds`: A unique identifier for the account, derived from the profile's recorded owner.
+    /// - `bump`: The bump stored in the profile.
+    ///
+    /// The owner's draft entries to publish are passed as writable remaining accounts. Each entry
+    /// that is released is followed by its audit log if it is audited, then by the entry index
+    /// page it is listed on. Drafts scheduled for later are skipped.
+    #[account(
+        seeds = [PROFILE_SEED.as_ref(), profile.owner.as_ref()],
+        bump = profile.bump,
+    )]
+    pub profile: Account<'info, UserProfile>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+}
+
+pub(crate) fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReleaseEntries<'info>>,
+) -> Result<()> {
+    let profile = &ctx.accounts.profile;
+    let now = Clock::get()?.unix_timestamp;
+    profile.check_inactive(now)?;
+
+    let mut released = 0;
+    let mut accounts = ctx.remaining_accounts.iter();
+    while let Some(info) = accounts.next() {
+        require!(info.is_writable, ErrorCode::ConstraintMut);
+        let mut journal_entry = Account::<JournalEntryState>::try_from(info)?;
+        require_keys_eq!(journal_entry.owner, profile.owner, JournalError::NotOwner);
+        // Trashed drafts were discarded by the owner and stay private. Scheduled drafts wait for
+        // their publish time, as they would for the owner.
+        if journal_entry.status == EntryStatus::Draft
+            && journal_entry.deleted_at.is_none()
+            && journal_entry.is_publish_time(now)
+        {
+            journal_entry.status = EntryStatus::Published;
+            // Nobody signs a release, so it is recorded on the owner's behalf.
+            journal_entry.audit_next(
+                journal_entry.key(),
+                &mut accounts,
+                profile.owner,
+                AuditAction::Published,
+            )?;
+            let page_info = accounts
+                .next()
+                .ok_or(JournalError::EntryIndexPageRequired)?;
+            require!(page_info.is_writable, ErrorCode::ConstraintMut);
+            let mut page = Account::<EntryIndexPage>::try_from(page_info)?;
+            page.list_published(&journal_entry)?;
+            page.exit(ctx.program_id)?;
+            journal_entry.exit(ctx.program_id)?;
+            released += 1;
+        }
+    }
+
+    msg!("Released {} journal entries of {}", released, profile.owner);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    /// Releases, at 1000, the drafts of an inactive owner that were scheduled for `publish_at`,
+    /// passing the due ones with their entry index page if `with_pages` is set. Returns the
+    /// accounts as left by the instruction.
+    fn release(publish_at: &[i64], with_pages: bool) -> Result<Vec<TestAccount>> {
+        set_now(1_000);
+        let owner = Pubkey::new_unique();
+        let (profile_key, mut profile) = profile(owner);
+        profile.release_after = Some(100);
+        let (page_key, page) = index_page(owner, 0);
+        let mut accounts = vec![
+            TestAccount::program(profile_key, &profile).read_only(),
+            TestAccount::config(&config()),
+            TestAccount::instructions(&crate::ID),
+        ];
+        for (index, publish_at) in publish_at.iter().enumerate() {
+            let (key, mut entry) = entry(owner, index as u64);
+            entry.publish_at = Some(*publish_at);
+            accounts.push(TestAccount::entry(key, &entry));
+            if with_pages && *publish_at <= 1_000 {
+                let space = 8 + EntryIndexPage::INIT_SPACE;
+                accounts.push(TestAccount::allocated(page_key, &page, space));
+            }
+        }
+        {
+            let infos = infos(&mut accounts);
+            let (named, remaining) = infos.split_at(3);
+            let (mut accts, bumps) = try_accounts::<ReleaseEntries>(named, &[])?;
+            handler(Context::new(&crate::ID, &mut accts, remaining, bumps))?;
+        }
+        Ok(accounts)
+    }
+
+    #[test]
+    fn released_drafts_are_published_and_listed() {
+        let accounts = release(&[1_000], true).unwrap();
+
+        let entry: JournalEntryState = accounts[3].state();
+        assert!(entry.status == EntryStatus::Published);
+        let page: EntryIndexPage = accounts[4].state();
+        assert_eq!(page.entries, vec![accounts[3].key]);
+    }
+
+    #[test]
+    fn scheduled_drafts_wait_for_their_publish_time() {
+        let accounts = release(&[1_001], true).unwrap();
+
+        let entry: JournalEntryState = accounts[3].state();
+        assert!(entry.status == EntryStatus::Draft);
+    }
+
+    #[test]
+    fn released_drafts_require_their_page() {
+        assert_eq!(
+            release(&[1_000], false).map(|_| ()),
+            Err(JournalError::EntryIndexPageRequired.into())
+        );
+    }
+}
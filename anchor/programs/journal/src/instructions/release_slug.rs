@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::JournalError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ReleaseSlug<'info> {
+    /// The slug account to be released.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `has_one`: The signer must hold the slug, otherwise `NotOwner` is returned.
+    /// - `close`: Closes the account and transfers the remaining lamports to the owner.
+    #[account(
+        mut,
+        has_one = owner @ JournalError::NotOwner,
+        close = owner,
+    )]
+    pub slug_record: Account<'info, SlugRecord>,
+    /// The wallet holding the slug.
+    /// This account must sign the transaction to authorize it.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<ReleaseSlug>) -> Result<()> {
+    msg!("Slug released: {}", ctx.accounts.slug_record.slug);
+
+    Ok(())
+}
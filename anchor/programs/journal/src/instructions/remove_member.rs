@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct RemoveMember<'info> {
+    /// The shared journal losing a member.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from its creator and identifier.
+    /// - `bump`: The bump stored in the journal.
+    #[account(
+        mut,
+        seeds = [
+            SHARED_JOURNAL_SEED.as_ref(),
+            shared_journal.creator.as_ref(),
+            &shared_journal.id.to_le_bytes(),
+        ],
+        bump = shared_journal.bump,
+    )]
+    pub shared_journal: Account<'info, SharedJournal>,
+    /// The admin's own membership, proving their role.
+    #[account(
+        seeds = [ROLE_SEED.as_ref(), shared_journal.key().as_ref(), admin.key().as_ref()],
+        bump,
+    )]
+    pub admin_membership: Account<'info, JournalMember>,
+    /// The membership being removed.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from the journal and the member's public key.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    ///
+    /// The member's entries to freeze or transfer are passed as writable remaining accounts, each
+    /// audited entry followed by its audit log. The handler closes the membership, refunding its rent to the removed member, once none of
+    /// their entries are left.
+    #[account(
+        mut,
+        seeds = [ROLE_SEED.as_ref(), shared_journal.key().as_ref(), member.key().as_ref()],
+        bump,
+    )]
+    pub membership: Account<'info, JournalMember>,
+    /// The removed member, who receives the membership's rent.
+    #[account(mut)]
+    pub member: SystemAccount<'info>,
+    /// The admin signing the transaction.
+    /// This account must sign the transaction to authorize it.
+    pub admin: Signer<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+}
+
+pub(crate) fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RemoveMember<'info>>,
+    policy: RetentionPolicy,
+) -> Result<()> {
+    ctx.accounts
+        .admin_membership
+        .check_role(MemberRole::Admin)?;
+    // Admins cannot remove themselves, so a journal never loses its last admin.
+    let member = ctx.accounts.member.key();
+    let admin = ctx.accounts.admin.key();
+    require_keys_neq!(member, admin, JournalError::CannotChangeOwnRole);
+
+    let shared_journal = &mut ctx.accounts.shared_journal;
+    let membership = &mut ctx.accounts.membership;
+
+    if policy != RetentionPolicy::Retain {
+        let mut accounts = ctx.remaining_accounts.iter();
+        while let Some(info) = accounts.next() {
+            require!(info.is_writable, ErrorCode::ConstraintMut);
+            let mut journal_entry = Account::<JournalEntryState>::try_from(info)?;
+            require_keys_eq!(
+                journal_entry.owner,
+                shared_journal.key(),
+                JournalError::NotInSharedJournal
+            );
+            require_keys_eq!(journal_entry.author, member, JournalError::NotMemberEntry);
+            // Frozen entries were already counted down by an earlier call.
+            journal_entry.check_not_sealed()?;
+            membership.record_entry_removed(&journal_entry);
+            let action = match policy {
+                RetentionPolicy::Freeze => {
+                    journal_entry.is_sealed = true;
+                    AuditAction::Sealed
+                }
+                RetentionPolicy::TransferToAdmin => {
+                    journal_entry.owner = admin;
+                    AuditAction::Transferred
+                }
+                RetentionPolicy::Retain => unreachable!(),
+            };
+            journal_entry.audit_next(journal_entry.key(), &mut accounts, admin, action)?;
+            journal_entry.exit(ctx.program_id)?;
+        }
+
+        // Until every entry is settled, the member stays on as a reader so they cannot add more.
+        if membership.entry_count > 0 {
+            membership.role = MemberRole::Reader;
+            msg!(
+                "Member {} has {} entries left to settle",
+                member,
+                membership.entry_count
+            );
+            return Ok(());
+        }
+    }
+
+    shared_journal.member_count -= 1;
+    membership.close(ctx.accounts.member.to_account_info())?;
+
+    msg!("Member {} removed from shared journal", member);
+
+    Ok(())
+}
@@ -0,0 +1,158 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(old_title: String, new_title: String)]
+pub struct RenameEntry<'info> {
+    /// The journal entry to be renamed.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from the entry's recorded author and index.
+    /// - `bump`: The bump stored in the entry.
+    /// - `has_one`: The signer must be the entry's owner, otherwise `NotOwner` is returned.
+    /// - `realloc`: Reallocates the account to fit the new title.
+    /// - `realloc::payer`: The account that will pay for the reallocation.
+    /// - `realloc::zero`: Ensures the newly allocated space is zeroed out.
+    #[account(
+        mut,
+        seeds = [
+            ENTRY_SEED.as_ref(),
+            journal_entry.author.as_ref(),
+            &journal_entry.index.to_le_bytes(),
+        ],
+        bump = journal_entry.bump,
+        has_one = owner @ JournalError::NotOwner,
+        realloc = JournalEntryState::space(new_title.len(), journal_entry.body_len()),
+        realloc::payer = payer,
+        realloc::zero = true,
+    )]
+    pub journal_entry: Account<'info, JournalEntryState>,
+    /// The signer of the transaction.
+    /// This account must sign the transaction to authorize it. It may be a program-derived
+    /// address signing through CPI, as it does not pay for anything.
+    pub owner: Signer<'info>,
+    /// The account paying rent for account creation and reallocation, and receiving the refund
+    /// when the entry shrinks. Usually the owner itself.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// The entry's audit log, required only if auditing is enabled for the entry.
+    #[account(mut, seeds = [AUDIT_LOG_SEED.as_ref(), journal_entry.key().as_ref()], bump)]
+    pub audit_log: Option<Account<'info, AuditLog>>,
+    /// The profile of the entry's author, whose statistics are updated.
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED.as_ref(), journal_entry.author.as_ref()],
+        bump = profile.bump,
+    )]
+    pub profile: Account<'info, UserProfile>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The system program required for account reallocation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<RenameEntry>,
+    old_title: String,
+    new_title: String,
+    expected_version: u64,
+) -> Result<()> {
+    ctx.accounts.journal_entry.check_editable()?;
+    ctx.accounts.journal_entry.check_version(expected_version)?;
+
+    let journal_entry = &mut ctx.accounts.journal_entry;
+    require!(
+        journal_entry.title == old_title,
+        JournalError::TitleMismatch
+    );
+    ctx.accounts
+        .cpi_guard
+        .config
+        .validate_entry(&new_title, &journal_entry.message)?;
+
+    msg!("Journal Entry Renamed");
+    msg!("Title: {} -> {}", old_title, new_title);
+
+    journal_entry.title = new_title;
+    journal_entry.touch(Clock::get()?.unix_timestamp);
+    ctx.accounts
+        .profile
+        .record_bytes_written(journal_entry.title.len());
+    journal_entry.audit(
+        ctx.accounts.audit_log.as_mut(),
+        ctx.accounts.owner.key(),
+        AuditAction::Updated,
+    )?;
+
+    ctx.accounts
+        .profile
+        .record_activity(ctx.accounts.owner.key(), Clock::get()?.unix_timestamp);
+
+    emit!(EntryUpdated {
+        owner: journal_entry.owner,
+        entry: journal_entry.key(),
+        title: journal_entry.title.clone(),
+        timestamp: journal_entry.updated_at,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    /// Renames an entry at version 1 from "old" to "new", as read at `expected_version`.
+    /// Returns the entry as left by the instruction.
+    fn rename(expected_version: u64) -> Result<JournalEntryState> {
+        set_now(100);
+        let owner = Pubkey::new_unique();
+        let (entry_key, mut entry) = entry(owner, 0);
+        entry.title = "old".to_string();
+        entry.version = 1;
+        let (profile_key, profile) = profile(owner);
+        let mut accounts = [
+            TestAccount::entry(entry_key, &entry),
+            TestAccount::signer(owner),
+            TestAccount::signer(owner),
+            TestAccount::absent(),
+            TestAccount::program(profile_key, &profile),
+            TestAccount::config(&config()),
+            TestAccount::instructions(&crate::ID),
+            TestAccount::executable(System::id()),
+        ];
+        let ix_data = ("old".to_string(), "new".to_string(), expected_version)
+            .try_to_vec()
+            .unwrap();
+        {
+            let infos = infos(&mut accounts);
+            let (mut accts, bumps) = try_accounts::<RenameEntry>(&infos, &ix_data)?;
+            handler(
+                Context::new(&crate::ID, &mut accts, &[], bumps),
+                "old".to_string(),
+                "new".to_string(),
+                expected_version,
+            )?;
+            accts.exit(&crate::ID)?;
+        }
+        Ok(accounts[0].state())
+    }
+
+    #[test]
+    fn renaming_the_version_read_succeeds() {
+        let entry = rename(1).unwrap();
+        assert_eq!(entry.title, "new");
+        assert_eq!(entry.version, 2);
+    }
+
+    #[test]
+    fn renaming_a_stale_copy_fails() {
+        assert_eq!(rename(0).err(), Some(JournalError::StaleVersion.into()));
+    }
+}
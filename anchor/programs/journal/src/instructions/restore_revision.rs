@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct RestoreRevision<'info> {
+    /// The journal entry to be restored.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from the entry's recorded author and index.
+    /// - `bump`: The bump stored in the entry.
+    /// - `has_one`: The signer must be the entry's owner, otherwise `NotOwner` is returned.
+    /// - `realloc`: Reallocates the account to fit the restored message.
+    /// - `realloc::payer`: The account that will pay for the reallocation.
+    /// - `realloc::zero`: Ensures the newly allocated space is zeroed out.
+    #[account(
+        mut,
+        seeds = [
+            ENTRY_SEED.as_ref(),
+            journal_entry.author.as_ref(),
+            &journal_entry.index.to_le_bytes(),
+        ],
+        bump = journal_entry.bump,
+        has_one = owner @ JournalError::NotOwner,
+        realloc = JournalEntryState::space(
+            journal_entry.title.len(),
+            revision_history.restored_len(journal_entry.message.len()),
+        ),
+        realloc::payer = payer,
+        realloc::zero = true,
+    )]
+    pub journal_entry: Account<'info, JournalEntryState>,
+    /// The signer of the transaction.
+    /// This account must sign the transaction to authorize it. It may be a program-derived
+    /// address signing through CPI, as it does not pay for anything.
+    pub owner: Signer<'info>,
+    /// The account paying rent for account creation and reallocation, and receiving the refund
+    /// when the entry shrinks. Usually the owner itself.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// The entry's revision history.
+    #[account(mut, seeds = [REVISION_SEED.as_ref(), journal_entry.key().as_ref()], bump)]
+    pub revision_history: Account<'info, RevisionHistory>,
+    /// The entry's audit log, required only if auditing is enabled for the entry.
+    #[account(mut, seeds = [AUDIT_LOG_SEED.as_ref(), journal_entry.key().as_ref()], bump)]
+    pub audit_log: Option<Account<'info, AuditLog>>,
+    /// The profile of the entry's author, whose statistics are updated.
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED.as_ref(), journal_entry.author.as_ref()],
+        bump = profile.bump,
+    )]
+    pub profile: Account<'info, UserProfile>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The system program required for account reallocation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<RestoreRevision>) -> Result<()> {
+    ctx.accounts.journal_entry.check_editable()?;
+    ctx.accounts.journal_entry.check_plaintext()?;
+
+    let revision = ctx
+        .accounts
+        .revision_history
+        .revisions
+        .pop()
+        .ok_or(JournalError::NoRevisions)?;
+
+    let journal_entry = &mut ctx.accounts.journal_entry;
+    let restored = revision
+        .apply(&journal_entry.message)
+        .ok_or(JournalError::RevisionMismatch)?;
+    journal_entry.message =
+        String::from_utf8(restored).map_err(|_| JournalError::RevisionMismatch)?;
+    journal_entry
+        .content_type
+        .validate(&journal_entry.message)?;
+    journal_entry.touch(Clock::get()?.unix_timestamp);
+    ctx.accounts
+        .profile
+        .record_bytes_written(journal_entry.message.len());
+    journal_entry.audit(
+        ctx.accounts.audit_log.as_mut(),
+        ctx.accounts.owner.key(),
+        AuditAction::Restored,
+    )?;
+
+    ctx.accounts
+        .profile
+        .record_activity(ctx.accounts.owner.key(), Clock::get()?.unix_timestamp);
+
+    emit!(EntryUpdated {
+        owner: journal_entry.owner,
+        entry: journal_entry.key(),
+        title: journal_entry.title.clone(),
+        timestamp: journal_entry.updated_at,
+    });
+
+    msg!("Journal Entry Restored");
+    msg!("Title: {}", journal_entry.title);
+
+    Ok(())
+}
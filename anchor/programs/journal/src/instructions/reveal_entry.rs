@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(title: String, message: String)]
+pub struct RevealEntry<'info> {
+    /// The private journal entry to be revealed.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from the entry's recorded author and index.
+    /// - `bump`: The bump stored in the entry.
+    /// - `has_one`: The signer must be the entry's owner, otherwise `NotOwner` is returned.
+    /// - `realloc`: Reallocates the account to fit the revealed content.
+    /// - `realloc::payer`: The account that will pay for the reallocation.
+    /// - `realloc::zero`: Ensures the newly allocated space is zeroed out.
+    #[account(
+        mut,
+        seeds = [
+            ENTRY_SEED.as_ref(),
+            journal_entry.author.as_ref(),
+            &journal_entry.index.to_le_bytes(),
+        ],
+        bump = journal_entry.bump,
+        has_one = owner @ JournalError::NotOwner,
+        realloc = JournalEntryState::space(title.len(), message.len()),
+        realloc::payer = payer,
+        realloc::zero = true,
+    )]
+    pub journal_entry: Account<'info, JournalEntryState>,
+    /// The signer of the transaction.
+    /// This account must sign the transaction to authorize it.
+    pub owner: Signer<'info>,
+    /// The account paying rent for the reallocation. Usually the owner itself.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// The profile of the entry's author, whose statistics are updated.
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED.as_ref(), journal_entry.author.as_ref()],
+        bump = profile.bump,
+    )]
+    pub profile: Account<'info, UserProfile>,
+    /// The entry's audit log, required only if auditing is enabled for the entry.
+    #[account(mut, seeds = [AUDIT_LOG_SEED.as_ref(), journal_entry.key().as_ref()], bump)]
+    pub audit_log: Option<Account<'info, AuditLog>>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The system program required for account reallocation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<RevealEntry>,
+    title: String,
+    message: String,
+    salt: [u8; 32],
+) -> Result<()> {
+    let journal_entry = &mut ctx.accounts.journal_entry;
+    let commitment = journal_entry
+        .commitment
+        .ok_or(JournalError::EntryNotCommitted)?;
+    // Entries created with a public title must reveal that same title.
+    require!(
+        JournalEntryState::commitment(&title, &message, &salt) == commitment
+            && (journal_entry.title.is_empty() || journal_entry.title == title),
+        JournalError::CommitmentMismatch
+    );
+    ctx.accounts
+        .cpi_guard
+        .config
+        .validate_entry(&title, &message)?;
+
+    // Revealing is not an edit: the content was fixed when the entry was created. It is only
+    // counted as written now, except for a title that was public from the start.
+    let revealed_len = message.len()
+        + if journal_entry.title.is_empty() {
+            title.len()
+        } else {
+            0
+        };
+    ctx.accounts.profile.record_bytes_written(revealed_len);
+    journal_entry.title = title;
+    journal_entry.message = message;
+    journal_entry.commitment = None;
+
+    ctx.accounts
+        .profile
+        .record_activity(ctx.accounts.owner.key(), Clock::get()?.unix_timestamp);
+
+    journal_entry.audit(
+        ctx.accounts.audit_log.as_mut(),
+        ctx.accounts.owner.key(),
+        AuditAction::Revealed,
+    )?;
+
+    emit!(EntryUpdated {
+        owner: journal_entry.owner,
+        entry: journal_entry.key(),
+        title: journal_entry.title.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Journal entry titled {} revealed", journal_entry.title);
+
+    Ok(())
+}
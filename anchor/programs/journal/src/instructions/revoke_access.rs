@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct RevokeAccess<'info> {
+    /// The grant to be revoked.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from the entry and the reader.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `has_one`: The signer must be the owner who granted access, otherwise `NotOwner` is returned.
+    /// - `close`: Closes the account and transfers the remaining lamports to the owner.
+    #[account(
+        mut,
+        seeds = [
+            ACCESS_GRANT_SEED.as_ref(),
+            access_grant.entry.as_ref(),
+            access_grant.reader.as_ref(),
+        ],
+        bump,
+        has_one = owner @ JournalError::NotOwner,
+        close = owner,
+    )]
+    pub access_grant: Account<'info, AccessGrant>,
+    /// The signer of the transaction.
+    /// This account must sign the transaction to authorize it.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<RevokeAccess>) -> Result<()> {
+    msg!("Access of {} revoked", ctx.accounts.access_grant.reader);
+
+    Ok(())
+}
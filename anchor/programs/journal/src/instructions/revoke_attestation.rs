@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::JournalError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct RevokeAttestation<'info> {
+    /// The attestation to be revoked.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `has_one`: Only the original attester can revoke the attestation.
+    /// - `close`: Closes the account and transfers the remaining lamports to the attester.
+    #[account(
+        mut,
+        has_one = attester @ JournalError::NotAttester,
+        close = attester,
+    )]
+    pub attestation: Account<'info, Attestation>,
+    /// The attester signing the transaction.
+    /// This account must sign the transaction to authorize it.
+    #[account(mut)]
+    pub attester: Signer<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+}
+
+pub(crate) fn handler(_ctx: Context<RevokeAttestation>) -> Result<()> {
+    msg!("Attestation revoked");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    fn try_revoke_attestation(attester: Pubkey, signer: Pubkey) -> Result<()> {
+        let attestation = Attestation {
+            attester,
+            entry: Pubkey::new_unique(),
+            role: 0,
+            statement_hash: None,
+            attested_at: 0,
+        };
+        let mut accounts = [
+            TestAccount::program(Pubkey::new_unique(), &attestation),
+            TestAccount::signer(signer),
+            TestAccount::config(&config()),
+            TestAccount::instructions(&crate::ID),
+        ];
+        try_accounts::<RevokeAttestation>(&infos(&mut accounts), &[]).map(|_| ())
+    }
+
+    #[test]
+    fn attester_can_revoke_the_attestation() {
+        let attester = Pubkey::new_unique();
+        assert!(try_revoke_attestation(attester, attester).is_ok());
+    }
+
+    #[test]
+    fn another_signer_is_not_the_attester() {
+        let result = try_revoke_attestation(Pubkey::new_unique(), Pubkey::new_unique());
+        assert_eq!(result, Err(JournalError::NotAttester.into()));
+    }
+}
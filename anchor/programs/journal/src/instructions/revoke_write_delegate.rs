@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct RevokeWriteDelegate<'info> {
+    /// The delegation to be revoked.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from the owner and delegate public keys.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `close`: Closes the account and transfers the remaining lamports to the owner.
+    #[account(
+        mut,
+        seeds = [
+            DELEGATE_SEED.as_ref(),
+            owner.key().as_ref(),
+            write_delegate.delegate.as_ref(),
+        ],
+        bump,
+        close = owner,
+    )]
+    pub write_delegate: Account<'info, WriteDelegate>,
+    /// The signer of the transaction.
+    /// This account must sign the transaction to authorize it.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<RevokeWriteDelegate>) -> Result<()> {
+    msg!(
+        "Write delegate {} revoked",
+        ctx.accounts.write_delegate.delegate
+    );
+
+    Ok(())
+}
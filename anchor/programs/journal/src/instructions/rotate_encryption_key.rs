@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct RotateEncryptionKey<'info> {
+    /// The owner's key registry.
+    ///
+    /// - `init_if_needed`: Creates the registry when the owner publishes their first key.
+    /// - `seeds`: A unique identifier for the account, derived from the owner's public key.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init_if_needed,
+        seeds = [KEY_REGISTRY_SEED.as_ref(), owner.key().as_ref()],
+        bump,
+        payer = owner,
+        space = 8 + KeyRegistry::INIT_SPACE
+    )]
+    pub key_registry: Account<'info, KeyRegistry>,
+    /// The signer of the transaction.
+    /// This account must sign the transaction to authorize it.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The system program required for account creation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<RotateEncryptionKey>,
+    encryption_pubkey: [u8; 32],
+) -> Result<()> {
+    let key_registry = &mut ctx.accounts.key_registry;
+    key_registry.rotate(
+        ctx.accounts.owner.key(),
+        encryption_pubkey,
+        Clock::get()?.unix_timestamp,
+    );
+
+    msg!(
+        "Encryption key rotated to version {}",
+        key_registry.key_version
+    );
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SetMemberRole<'info> {
+    /// The admin's own membership, proving their role.
+    #[account(
+        seeds = [ROLE_SEED.as_ref(), membership.journal.as_ref(), admin.key().as_ref()],
+        bump,
+    )]
+    pub admin_membership: Account<'info, JournalMember>,
+    /// The membership whose role is being changed.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from the journal and the member's public key.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    #[account(
+        mut,
+        seeds = [
+            ROLE_SEED.as_ref(),
+            membership.journal.as_ref(),
+            membership.member.as_ref(),
+        ],
+        bump,
+    )]
+    pub membership: Account<'info, JournalMember>,
+    /// The admin signing the transaction.
+    /// This account must sign the transaction to authorize it.
+    pub admin: Signer<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<SetMemberRole>, role: MemberRole) -> Result<()> {
+    ctx.accounts
+        .admin_membership
+        .check_role(MemberRole::Admin)?;
+    // Admins cannot demote themselves, so a journal never loses its last admin.
+    let membership = &mut ctx.accounts.membership;
+    require_keys_neq!(
+        membership.member,
+        ctx.accounts.admin.key(),
+        JournalError::CannotChangeOwnRole
+    );
+    membership.role = role;
+
+    msg!("Role of member {} changed", membership.member);
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SetReleaseDelay<'info> {
+    /// The owner's profile.
+    ///
+    /// - `init_if_needed`: Creates the profile if the owner has no entries yet.
+    /// - `seeds`: A unique identifier for the account, derived from the owner's public key.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init_if_needed,
+        seeds = [PROFILE_SEED.as_ref(), owner.key().as_ref()],
+        bump,
+        payer = owner,
+        space = 8 + UserProfile::INIT_SPACE
+    )]
+    pub profile: Account<'info, UserProfile>,
+    /// The signer of the transaction.
+    /// This account must sign the transaction to authorize it.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The system program required for account creation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<SetReleaseDelay>, release_after: Option<i64>) -> Result<()> {
+    if let Some(release_after) = release_after {
+        require!(release_after > 0, JournalError::InvalidReleaseDelay);
+    }
+
+    let profile = &mut ctx.accounts.profile;
+    // Configuring the switch is itself a sign of life, so it never fires immediately.
+    profile.record_heartbeat(
+        ctx.accounts.owner.key(),
+        ctx.bumps.profile,
+        Clock::get()?.unix_timestamp,
+    );
+    profile.release_after = release_after;
+
+    msg!("Release delay set to {:?} seconds", release_after);
+
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SetTags<'info> {
+    /// The journal entry whose tags are being replaced.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from the entry's recorded author and index.
+    /// - `bump`: The bump stored in the entry.
+    /// - `has_one`: The signer must be the entry's owner, otherwise `NotOwner` is returned.
+    #[account(
+        mut,
+        seeds = [
+            ENTRY_SEED.as_ref(),
+            journal_entry.author.as_ref(),
+            &journal_entry.index.to_le_bytes(),
+        ],
+        bump = journal_entry.bump,
+        has_one = owner @ JournalError::NotOwner,
+    )]
+    pub journal_entry: Account<'info, JournalEntryState>,
+    /// The signer of the transaction.
+    /// This account must sign the transaction to authorize it.
+    pub owner: Signer<'info>,
+    /// The entry's audit log, required only if auditing is enabled for the entry.
+    #[account(mut, seeds = [AUDIT_LOG_SEED.as_ref(), journal_entry.key().as_ref()], bump)]
+    pub audit_log: Option<Account<'info, AuditLog>>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<SetTags>, tags: Vec<String>) -> Result<()> {
+    JournalEntryState::validate_tags(&tags)?;
+
+    let journal_entry = &mut ctx.accounts.journal_entry;
+    journal_entry.check_editable()?;
+    journal_entry.tags = tags;
+
+    journal_entry.audit(
+        ctx.accounts.audit_log.as_mut(),
+        ctx.accounts.owner.key(),
+        AuditAction::MetadataUpdated,
+    )?;
+
+    msg!("Journal entry tagged {:?}", journal_entry.tags);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    fn set_tags(audited: bool, with_log: bool) -> Result<Option<AuditLog>> {
+        set_now(100);
+        let owner = Pubkey::new_unique();
+        let (key, mut entry) = entry(owner, 0);
+        entry.audited = audited;
+        let (log_key, _) =
+            Pubkey::find_program_address(&[AUDIT_LOG_SEED.as_ref(), key.as_ref()], &crate::ID);
+        let audit_log = AuditLog {
+            entry: key,
+            total_records: 0,
+            next_index: 0,
+            records: Vec::new(),
+        };
+        let mut accounts = [
+            TestAccount::program(key, &entry),
+            TestAccount::signer(owner),
+            if with_log {
+                TestAccount::program(log_key, &audit_log)
+            } else {
+                TestAccount::absent()
+            },
+            TestAccount::config(&config()),
+            TestAccount::instructions(&crate::ID),
+        ];
+        let infos = infos(&mut accounts);
+        let (mut set_tags, bumps) = try_accounts::<SetTags>(&infos, &[])?;
+        handler(
+            Context::new(&crate::ID, &mut set_tags, &[], bumps),
+            vec!["travel".to_string()],
+        )?;
+        Ok(set_tags.audit_log.map(|audit_log| audit_log.into_inner()))
+    }
+
+    #[test]
+    fn tagging_an_audited_entry_is_logged() {
+        let audit_log = set_tags(true, true).unwrap().unwrap();
+        assert_eq!(audit_log.total_records, 1);
+        assert!(audit_log.records[0].action == AuditAction::MetadataUpdated);
+    }
+
+    #[test]
+    fn tagging_an_audited_entry_requires_its_log() {
+        assert_eq!(
+            set_tags(true, false).map(|_| ()),
+            Err(JournalError::AuditLogRequired.into())
+        );
+        assert!(set_tags(false, false).is_ok());
+    }
+}
@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SetTocOrder<'info> {
+    /// The journal's table of contents.
+    ///
+    /// - `init_if_needed`: Initializes the account if it doesn't already exist.
+    /// - `seeds`: A unique identifier for the account, derived from the named journal, or the owner's public key for their default journal.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init_if_needed,
+        seeds = [
+            TOC_SEED.as_ref(),
+            TableOfContents::scope(owner.key(), journal.as_ref()).as_ref(),
+        ],
+        bump,
+        payer = owner,
+        space = 8 + TableOfContents::INIT_SPACE
+    )]
+    pub table_of_contents: Account<'info, TableOfContents>,
+    /// The named journal being ordered, or `None` for the owner's default journal.
+    ///
+    /// The entries referenced by the items are passed, in the same order, as remaining accounts.
+    #[account(has_one = owner @ JournalError::NotOwner)]
+    pub journal: Option<Account<'info, Journal>>,
+    /// The signer of the transaction.
+    /// This account must sign the transaction to authorize it.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The system program required for account creation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SetTocOrder<'info>>,
+    items: Vec<TocItem>,
+) -> Result<()> {
+    require!(
+        items.len() <= MAX_TOC_ITEMS as usize,
+        JournalError::TooManyTocItems
+    );
+    let owner = ctx.accounts.owner.key();
+    let journal = ctx.accounts.journal.as_ref().map(|journal| journal.key());
+    let mut entries = ctx.remaining_accounts.iter();
+    for item in &items {
+        match item {
+            TocItem::Section { header } => require!(
+                header.len() <= MAX_SECTION_HEADER_LENGTH as usize,
+                JournalError::SectionHeaderTooLong
+            ),
+            TocItem::Entry { entry } => {
+                let info = entries.next().ok_or(JournalError::TocEntriesMismatch)?;
+                require_keys_eq!(info.key(), *entry, JournalError::TocEntriesMismatch);
+                let journal_entry = Account::<JournalEntryState>::try_from(info)?;
+                require_keys_eq!(journal_entry.owner, owner, JournalError::NotOwner);
+                require!(
+                    journal_entry.journal == journal,
+                    JournalError::JournalMismatch
+                );
+                // The table is public, so it cannot give away drafts.
+                journal_entry.check_published()?;
+            }
+        }
+    }
+    require!(entries.next().is_none(), JournalError::TocEntriesMismatch);
+
+    let toc = &mut ctx.accounts.table_of_contents;
+    toc.owner = owner;
+    toc.journal = journal;
+    toc.items = items;
+
+    msg!("Table of contents updated with {} items", toc.items.len());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    fn set_toc_order(status: EntryStatus) -> Result<()> {
+        let owner = Pubkey::new_unique();
+        let (toc_key, _) =
+            Pubkey::find_program_address(&[TOC_SEED.as_ref(), owner.as_ref()], &crate::ID);
+        let toc = TableOfContents {
+            owner,
+            journal: None,
+            items: Vec::new(),
+        };
+        let (entry_key, mut entry) = entry(owner, 0);
+        entry.status = status;
+        let mut accounts = [
+            TestAccount::allocated(toc_key, &toc, 8 + TableOfContents::INIT_SPACE),
+            TestAccount::absent(),
+            TestAccount::signer(owner),
+            TestAccount::config(&config()),
+            TestAccount::instructions(&crate::ID),
+            TestAccount::executable(System::id()),
+            TestAccount::entry(entry_key, &entry).read_only(),
+        ];
+        let infos = infos(&mut accounts);
+        let (named, remaining) = infos.split_at(6);
+        let (mut accts, bumps) = try_accounts::<SetTocOrder>(named, &[])?;
+        handler(
+            Context::new(&crate::ID, &mut accts, remaining, bumps),
+            vec![TocItem::Entry { entry: entry_key }],
+        )
+    }
+
+    #[test]
+    fn published_entries_can_be_listed() {
+        assert!(set_toc_order(EntryStatus::Published).is_ok());
+    }
+
+    #[test]
+    fn drafts_cannot_be_listed() {
+        assert_eq!(
+            set_toc_order(EntryStatus::Draft),
+            Err(JournalError::EntryNotPublished.into())
+        );
+    }
+}
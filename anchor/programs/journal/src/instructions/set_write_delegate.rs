@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct SetWriteDelegate<'info> {
+    /// The delegation to be created or replaced.
+    ///
+    /// - `init_if_needed`: Creates the delegation on first use; later calls replace its terms.
+    /// - `seeds`: A unique identifier for the account, derived from the owner and delegate public keys.
+    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
+    /// - `payer`: The account that will pay for the account creation.
+    /// - `space`: The amount of space to allocate for the account.
+    #[account(
+        init_if_needed,
+        seeds = [DELEGATE_SEED.as_ref(), owner.key().as_ref(), delegate.as_ref()],
+        bump,
+        payer = owner,
+        space = 8 + WriteDelegate::INIT_SPACE
+    )]
+    pub write_delegate: Account<'info, WriteDelegate>,
+    /// The signer of the transaction.
+    /// This account must sign the transaction to authorize it.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The system program required for account creation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<SetWriteDelegate>,
+    delegate: Pubkey,
+    scope: DelegateScope,
+    expires_at: i64,
+) -> Result<()> {
+    ctx.accounts.write_delegate.set_inner(WriteDelegate {
+        owner: ctx.accounts.owner.key(),
+        delegate,
+        scope,
+        expires_at,
+    });
+
+    msg!("Write delegate {} valid until {}", delegate, expires_at);
+
+    Ok(())
+}
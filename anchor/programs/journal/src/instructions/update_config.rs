@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    /// The program configuration to be updated.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `has_one`: Only the configuration authority can update it.
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_ref()],
+        bump,
+        has_one = authority,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    /// The configuration authority.
+    pub authority: Signer<'info>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<UpdateConfig>,
+    reject_cpi: bool,
+    cpi_allowlist: Vec<Pubkey>,
+) -> Result<()> {
+    require!(
+        cpi_allowlist.len() <= MAX_CPI_ALLOWLIST as usize,
+        JournalError::CpiAllowlistTooLong
+    );
+
+    let config = &mut ctx.accounts.config;
+    config.reject_cpi = reject_cpi;
+    config.cpi_allowlist = cpi_allowlist;
+
+    msg!("Program config updated");
+
+    Ok(())
+}
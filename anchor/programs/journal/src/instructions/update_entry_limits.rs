@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct UpdateEntryLimits<'info> {
+    /// The program configuration to be updated.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `has_one`: Only the configuration authority can update it.
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_ref()],
+        bump,
+        has_one = authority,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    /// The configuration authority.
+    pub authority: Signer<'info>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<UpdateEntryLimits>,
+    max_title_len: u32,
+    max_message_len: u32,
+) -> Result<()> {
+    require!(
+        (1..=MAX_TITLE_LENGTH).contains(&max_title_len)
+            && (1..=MAX_MESSAGE_LENGTH).contains(&max_message_len),
+        JournalError::InvalidEntryLimits
+    );
+
+    let config = &mut ctx.accounts.config;
+    config.max_title_len = max_title_len;
+    config.max_message_len = max_message_len;
+
+    msg!("Entry limits updated");
+
+    Ok(())
+}
@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(title: String, message: String)]
+pub struct UpdateEntry<'info> {
+    /// The account to be updated for the journal entry.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from the entry's recorded author and index.
+    /// - `bump`: The bump stored in the entry.
+    /// - `has_one`: The signer must be the entry's owner, otherwise `NotOwner` is returned.
+    /// - `realloc`: Reallocates the account with the new size.
+    /// - `realloc::payer`: The account that will pay for the reallocation.
+    /// - `realloc::zero`: Ensures the newly allocated space is zeroed out.
+    #[account(
+        mut,
+        seeds = [
+            ENTRY_SEED.as_ref(),
+            journal_entry.author.as_ref(),
+            &journal_entry.index.to_le_bytes(),
+        ],
+        bump = journal_entry.bump,
+        has_one = owner @ JournalError::NotOwner,
+        realloc = JournalEntryState::space(title.len(), message.len()),
+        realloc::payer = payer,
+        realloc::zero = true,
+    )]
+    pub journal_entry: Account<'info, JournalEntryState>,
+    /// The signer of the transaction.
+    /// This account must sign the transaction to authorize it. It may be a program-derived
+    /// address signing through CPI, as it does not pay for anything.
+    pub owner: Signer<'info>,
+    /// The account paying rent for account creation and reallocation, and receiving the refund
+    /// when the entry shrinks. Usually the owner itself.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// The profile of the entry's author, whose statistics are updated.
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED.as_ref(), journal_entry.author.as_ref()],
+        bump = profile.bump,
+    )]
+    pub profile: Account<'info, UserProfile>,
+    /// The entry's audit log, required only if auditing is enabled for the entry.
+    #[account(mut, seeds = [AUDIT_LOG_SEED.as_ref(), journal_entry.key().as_ref()], bump)]
+    pub audit_log: Option<Account<'info, AuditLog>>,
+    /// The entry's revision history, required only if revisions are enabled for the entry.
+    #[account(mut, seeds = [REVISION_SEED.as_ref(), journal_entry.key().as_ref()], bump)]
+    pub revision_history: Option<Account<'info, RevisionHistory>>,
+    /// A snapshot of the entry's content before the update, written only if provided.
+    ///
+    /// - `init`: Creates a new snapshot account for the entry's current edit count.
+    /// - `payer`: The payer funds the snapshot; `prune_revisions` returns its rent to the owner.
+    #[account(
+        init,
+        payer = payer,
+        space = JournalEntryRevision::space(journal_entry.title.len(), journal_entry.message.len()),
+        seeds = [
+            SNAPSHOT_SEED.as_ref(),
+            journal_entry.key().as_ref(),
+            &journal_entry.edit_count.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub snapshot: Option<Account<'info, JournalEntryRevision>>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The system program required for account reallocation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<UpdateEntry>,
+    title: String,
+    message: String,
+    expected_version: u64,
+) -> Result<()> {
+    ctx.accounts.journal_entry.check_editable()?;
+    ctx.accounts.journal_entry.check_version(expected_version)?;
+    ctx.accounts
+        .cpi_guard
+        .config
+        .validate_entry(&title, &message)?;
+    ctx.accounts.journal_entry.content_type.validate(&message)?;
+
+    // Log messages to the Solana runtime, useful for debugging.
+    msg!("Journal Entry Updated");
+    msg!("Title: {}", title);
+    msg!("Message: {}", message);
+
+    // Access the mutable reference to the journal entry account.
+    let now = Clock::get()?.unix_timestamp;
+    if let Some(snapshot) = ctx.accounts.snapshot.as_mut() {
+        snapshot.set_inner(JournalEntryRevision::capture(
+            &ctx.accounts.journal_entry,
+            now,
+        ));
+    }
+    let journal_entry = &mut ctx.accounts.journal_entry;
+    journal_entry.record_revision(ctx.accounts.revision_history.as_mut(), &message)?;
+    // Update the title and message of the journal entry.
+    journal_entry.title = title;
+    journal_entry.message = message;
+    journal_entry.clear_encryption();
+    journal_entry.touch(now);
+    ctx.accounts
+        .profile
+        .record_bytes_written(journal_entry.title.len() + journal_entry.message.len());
+    journal_entry.audit(
+        ctx.accounts.audit_log.as_mut(),
+        ctx.accounts.owner.key(),
+        AuditAction::Updated,
+    )?;
+
+    ctx.accounts
+        .profile
+        .record_activity(ctx.accounts.owner.key(), now);
+
+    emit!(EntryUpdated {
+        owner: journal_entry.owner,
+        entry: journal_entry.key(),
+        title: journal_entry.title.clone(),
+        timestamp: journal_entry.updated_at,
+    });
+
+    Ok(())
+}
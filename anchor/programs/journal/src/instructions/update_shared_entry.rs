@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(title: String, message: String)]
+pub struct UpdateSharedEntry<'info> {
+    /// The member's membership of the shared journal, proving their role.
+    #[account(
+        seeds = [ROLE_SEED.as_ref(), membership.journal.as_ref(), member.key().as_ref()],
+        bump,
+    )]
+    pub membership: Account<'info, JournalMember>,
+    /// The shared journal entry to be updated.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from the entry's recorded author and index.
+    /// - `bump`: The bump stored in the entry.
+    /// - `realloc`: Reallocates the account with the new size.
+    /// - `realloc::payer`: The account that will pay for the reallocation.
+    /// - `realloc::zero`: Ensures the newly allocated space is zeroed out.
+    #[account(
+        mut,
+        seeds = [
+            ENTRY_SEED.as_ref(),
+            journal_entry.author.as_ref(),
+            &journal_entry.index.to_le_bytes(),
+        ],
+        bump = journal_entry.bump,
+        realloc = JournalEntryState::space(title.len(), message.len()),
+        realloc::payer = member,
+        realloc::zero = true,
+    )]
+    pub journal_entry: Account<'info, JournalEntryState>,
+    /// The member editing the entry: an admin, or the writer who authored it.
+    /// This account must sign the transaction to authorize it.
+    #[account(mut)]
+    pub member: Signer<'info>,
+    /// The profile of the entry's author, whose statistics are updated.
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED.as_ref(), journal_entry.author.as_ref()],
+        bump = profile.bump,
+    )]
+    pub profile: Account<'info, UserProfile>,
+    /// The entry's audit log, required only if auditing is enabled for the entry.
+    #[account(mut, seeds = [AUDIT_LOG_SEED.as_ref(), journal_entry.key().as_ref()], bump)]
+    pub audit_log: Option<Account<'info, AuditLog>>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+    /// The system program required for account reallocation.
+    /// This is a built-in program that provides basic account management functionalities.
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<UpdateSharedEntry>,
+    title: String,
+    message: String,
+    expected_version: u64,
+) -> Result<()> {
+    ctx.accounts
+        .membership
+        .check_can_modify(&ctx.accounts.journal_entry)?;
+    ctx.accounts.journal_entry.check_editable()?;
+    ctx.accounts.journal_entry.check_version(expected_version)?;
+    ctx.accounts
+        .cpi_guard
+        .config
+        .validate_entry(&title, &message)?;
+
+    let journal_entry = &mut ctx.accounts.journal_entry;
+    journal_entry.content_type.validate(&message)?;
+
+    msg!("Shared Journal Entry Updated");
+    msg!("Title: {}", title);
+
+    journal_entry.title = title;
+    journal_entry.message = message;
+    journal_entry.clear_encryption();
+    journal_entry.touch(Clock::get()?.unix_timestamp);
+    ctx.accounts
+        .profile
+        .record_bytes_written(journal_entry.title.len() + journal_entry.message.len());
+
+    ctx.accounts
+        .profile
+        .record_activity(ctx.accounts.member.key(), Clock::get()?.unix_timestamp);
+
+    journal_entry.audit(
+        ctx.accounts.audit_log.as_mut(),
+        ctx.accounts.member.key(),
+        AuditAction::Updated,
+    )?;
+
+    emit!(EntryUpdated {
+        owner: journal_entry.owner,
+        entry: journal_entry.key(),
+        title: journal_entry.title.clone(),
+        timestamp: journal_entry.updated_at,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct UpdateTrashGracePeriod<'info> {
+    /// The program configuration to be updated.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `has_one`: Only the configuration authority can update it.
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_ref()],
+        bump,
+        has_one = authority,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    /// The configuration authority.
+    pub authority: Signer<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<UpdateTrashGracePeriod>, grace_period: i64) -> Result<()> {
+    require!(grace_period >= 0, JournalError::InvalidGracePeriod);
+
+    ctx.accounts.config.trash_grace_period = grace_period;
+
+    msg!("Trash grace period updated to {} seconds", grace_period);
+
+    Ok(())
+}
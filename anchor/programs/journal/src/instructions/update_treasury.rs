@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct UpdateTreasury<'info> {
+    /// The program configuration to be updated.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `has_one`: Only the configuration authority can update it.
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED.as_ref()],
+        bump,
+        has_one = authority,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+    /// The configuration authority.
+    pub authority: Signer<'info>,
+}
+
+pub(crate) fn handler(
+    ctx: Context<UpdateTreasury>,
+    treasury: Pubkey,
+    treasury_fee_bps: u16,
+    slug_claim_fee: u64,
+) -> Result<()> {
+    require!(treasury_fee_bps <= MAX_FEE_BPS, JournalError::InvalidFeeBps);
+
+    let config = &mut ctx.accounts.config;
+    config.treasury = treasury;
+    config.treasury_fee_bps = treasury_fee_bps;
+    config.slug_claim_fee = slug_claim_fee;
+
+    msg!("Treasury updated");
+
+    Ok(())
+}
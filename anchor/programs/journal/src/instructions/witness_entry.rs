@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::events::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct WitnessEntry<'info> {
+    /// The journal entry being witnessed.
+    ///
+    /// - `mut`: The account is mutable, meaning it can be modified.
+    /// - `seeds`: A unique identifier for the account, derived from the entry's recorded author and index.
+    /// - `bump`: The bump stored in the entry.
+    #[account(
+        mut,
+        seeds = [
+            ENTRY_SEED.as_ref(),
+            journal_entry.author.as_ref(),
+            &journal_entry.index.to_le_bytes(),
+        ],
+        bump = journal_entry.bump,
+    )]
+    pub journal_entry: Account<'info, JournalEntryState>,
+    /// The witness signing the transaction.
+    /// This account must sign the transaction to authorize it.
+    pub witness: Signer<'info>,
+    /// The entry's audit log, required only if auditing is enabled for the entry.
+    #[account(mut, seeds = [AUDIT_LOG_SEED.as_ref(), journal_entry.key().as_ref()], bump)]
+    pub audit_log: Option<Account<'info, AuditLog>>,
+    /// The program configuration and instructions sysvar, enforcing the CPI policy.
+    pub cpi_guard: CpiGuard<'info>,
+}
+
+pub(crate) fn handler(ctx: Context<WitnessEntry>, content_hash: [u8; 32]) -> Result<()> {
+    let journal_entry = &mut ctx.accounts.journal_entry;
+    let witness = ctx.accounts.witness.key();
+    journal_entry.check_not_trashed()?;
+    require!(
+        journal_entry.witness == Some(witness),
+        JournalError::NotWitness
+    );
+    // The witness names the content it read, so an edit racing the signature cannot be signed.
+    require!(
+        content_hash == journal_entry.content_hash(),
+        JournalError::ContentHashMismatch
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    journal_entry.witnessed_hash = Some(content_hash);
+    journal_entry.witnessed_at = now;
+    journal_entry.audit(
+        ctx.accounts.audit_log.as_mut(),
+        witness,
+        AuditAction::Witnessed,
+    )?;
+
+    emit!(EntryWitnessed {
+        witness,
+        entry: journal_entry.key(),
+        content_hash,
+        timestamp: now,
+    });
+
+    msg!("Journal entry titled {} witnessed", journal_entry.title);
+
+    Ok(())
+}
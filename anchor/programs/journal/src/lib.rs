@@ -1,6 +1,18 @@
 /// Imports necessary items from the Anchor framework.
 use anchor_lang::prelude::*;
 
+pub mod constants;
+pub mod errors;
+pub mod events;
+pub mod instructions;
+pub mod state;
+#[cfg(test)]
+mod test_utils;
+
+pub use constants::*;
+pub use instructions::*;
+pub use state::*;
+
 // This is your program's public key and it will update automatically when you build the project.
 // The `declare_id!` macro sets the program's unique identifier, which is essential for deploying
 // and interacting with the program on the Solana blockchain.
@@ -8,10 +20,112 @@ declare_id!("4yt2ZeKvCQYGKCnG8WoibHSebf5d5pGZWCeALTHMZZ71");
 
 /// The main program module for the journal.
 /// The `#[program]` attribute macro defines the entry points for the Solana program.
+/// Each entry point delegates to its handler in the `instructions` module.
 #[program]
 pub mod journal {
     use super::*;
 
+    /// Initializes the program configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// This function can only be called once, by the program's upgrade authority, who becomes
+    /// the configuration authority. CPI protection starts disabled, the authority receives
+    /// treasury fees, which start at zero, and entry limits start at their maximum.
+    pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+        instructions::initialize_config::handler(ctx)
+    }
+
+    /// Updates the program's CPI policy.
+    ///
+    /// The policy applies to every instruction except those administering the configuration, so
+    /// an authority held by another program can always change it. Only direct CPIs can be
+    /// allowlisted; nested ones are rejected whenever `reject_cpi` is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `reject_cpi` - Whether write instructions invoked via CPI should be rejected.
+    /// * `cpi_allowlist` - Programs allowed to invoke write instructions directly via CPI when `reject_cpi` is set.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        reject_cpi: bool,
+        cpi_allowlist: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::update_config::handler(ctx, reject_cpi, cpi_allowlist)
+    }
+
+    /// Updates the maximum title and message lengths accepted for entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `max_title_len` - The maximum title length, in bytes, up to `MAX_TITLE_LENGTH`.
+    /// * `max_message_len` - The maximum message length, in bytes, up to `MAX_MESSAGE_LENGTH`.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// The compile-time maxima bound the limits because revision histories and pending entries
+    /// reserve space for the largest possible message. Existing entries longer than a lowered
+    /// limit stay readable but must fit the new limit on their next edit.
+    pub fn update_entry_limits(
+        ctx: Context<UpdateEntryLimits>,
+        max_title_len: u32,
+        max_message_len: u32,
+    ) -> Result<()> {
+        instructions::update_entry_limits::handler(ctx, max_title_len, max_message_len)
+    }
+
+    /// Updates how long deleted entries stay in the trash before they can be purged.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `grace_period` - The grace period, in seconds. Zero allows purging immediately.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    pub fn update_trash_grace_period(
+        ctx: Context<UpdateTrashGracePeriod>,
+        grace_period: i64,
+    ) -> Result<()> {
+        instructions::update_trash_grace_period::handler(ctx, grace_period)
+    }
+
+    /// Updates the treasury and the fees it collects.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `treasury` - The account receiving treasury fees.
+    /// * `treasury_fee_bps` - The treasury's share of each sale, in basis points.
+    /// * `slug_claim_fee` - The fee for claiming a slug, in lamports.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    pub fn update_treasury(
+        ctx: Context<UpdateTreasury>,
+        treasury: Pubkey,
+        treasury_fee_bps: u16,
+        slug_claim_fee: u64,
+    ) -> Result<()> {
+        instructions::update_treasury::handler(ctx, treasury, treasury_fee_bps, slug_claim_fee)
+    }
+
     /// Creates a new journal entry.
     ///
     /// # Arguments
@@ -19,31 +133,206 @@ pub mod journal {
     /// * `ctx` - The context containing the accounts involved in the transaction.
     /// * `title` - The title of the journal entry.
     /// * `message` - The message of the journal entry.
+    /// * `options` - The entry's draft status, schedule, tags, category, content type, language,
+    ///   weather and the prompt it answers.
     ///
     /// # Returns
     ///
     /// * `Result<()>` - Returns an empty result on success.
     ///
     /// This function initializes a new journal entry account with the provided title and message.
-    /// It logs the creation of the entry and assigns the owner's public key to the entry.
+    /// The entry's address is derived from the owner and the next index in their profile, which is
+    /// created with the first entry, so titles need not be unique. It logs the creation of the
+    /// entry and assigns the owner's public key to the entry. Rent is paid by the `payer`
+    /// account, which a relayer can fill so that owners without SOL only sign to authorize.
+    /// Passing one of the owner's named journals files the entry in it. An entry answering a
+    /// prompt references it, which requires the owner to have bought the prompt's pack.
     pub fn create_journal_entry(
         ctx: Context<CreateEntry>,
         title: String,
         message: String,
+        options: EntryOptions,
+    ) -> Result<()> {
+        instructions::create_journal_entry::handler(ctx, title, message, options)
+    }
+
+    /// Creates a new journal entry on behalf of an owner who signed an off-chain permit.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `title` - The title of the journal entry.
+    /// * `message` - The message of the journal entry.
+    /// * `nonce` - A value chosen by the owner that makes the permit single-use.
+    /// * `expiry` - The Unix timestamp after which the permit is no longer valid.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// The transaction must include an Ed25519 program instruction, immediately before this one,
+    /// verifying the owner's signature over the serialized `EntryPermit`. The relayer pays the
+    /// fees and rent, so the owner needs no SOL. Each nonce can be used only once per owner.
+    pub fn create_entry_with_permit(
+        ctx: Context<CreateEntryWithPermit>,
+        title: String,
+        message: String,
+        nonce: u64,
+        expiry: i64,
+    ) -> Result<()> {
+        instructions::create_entry_with_permit::handler(ctx, title, message, nonce, expiry)
+    }
+
+    /// Creates a private journal entry with a public title that stores only a commitment to
+    /// its content.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `title` - The title of the journal entry, which is public.
+    /// * `commitment` - The commitment to the title, message and a secret salt, as described
+    ///   in `reveal_entry`.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// The entry proves that the message existed at creation time without publishing it. Until
+    /// it is revealed with `reveal_entry`, its content cannot be edited.
+    pub fn create_private_entry(
+        ctx: Context<CreatePrivateEntry>,
+        title: String,
+        commitment: [u8; 32],
+    ) -> Result<()> {
+        instructions::create_private_entry::handler(ctx, title, commitment)
+    }
+
+    /// Creates a journal entry that stores only a commitment to its content, keeping even the
+    /// title secret.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `commitment` - The commitment to the title, message and a secret salt, as described
+    ///   in `reveal_entry`.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// The entry's address and creation time prove the content existed before it is made
+    /// public, as for predictions.
+    pub fn commit_entry(ctx: Context<CommitEntry>, commitment: [u8; 32]) -> Result<()> {
+        instructions::commit_entry::handler(ctx, commitment)
+    }
+
+    /// Reveals the content of a private journal entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `title` - The title committed to at creation.
+    /// * `message` - The message committed to at creation.
+    /// * `salt` - The random salt that kept the commitment from being guessed.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// The commitment is the SHA-256 hash of the title's length as a little-endian `u32`, the
+    /// title, the message and the salt. Fails with `CommitmentMismatch` unless the content hashes
+    /// to it and, for entries with a public title, the title is unchanged. Revealing does not
+    /// count as an edit.
+    pub fn reveal_entry(
+        ctx: Context<RevealEntry>,
+        title: String,
+        message: String,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        instructions::reveal_entry::handler(ctx, title, message, salt)
+    }
+
+    /// Publishes a new X25519 public key for encrypting the owner's entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `encryption_pubkey` - The new public key.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Each key gets the next version number. New encrypted writes must use the current key;
+    /// existing entries keep the version they were encrypted under.
+    pub fn rotate_encryption_key(
+        ctx: Context<RotateEncryptionKey>,
+        encryption_pubkey: [u8; 32],
+    ) -> Result<()> {
+        instructions::rotate_encryption_key::handler(ctx, encryption_pubkey)
+    }
+
+    /// Shares an encrypted journal entry with a reader.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `reader` - The public key of the reader.
+    /// * `wrapped_key` - The entry's decryption key, encrypted to the reader's X25519 key.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Granting again replaces the wrapped key, which is needed after the entry is rewritten.
+    /// The grant only records the key: anyone can read the ciphertext, and only the reader can
+    /// unwrap the key.
+    pub fn grant_access(
+        ctx: Context<GrantAccess>,
+        reader: Pubkey,
+        wrapped_key: Vec<u8>,
     ) -> Result<()> {
-        // Log messages to the Solana runtime, useful for debugging.
-        msg!("Journal Entry Created");
-        msg!("Title: {}", title);
-        msg!("Message: {}", message);
+        instructions::grant_access::handler(ctx, reader, wrapped_key)
+    }
+
+    /// Revokes a reader's access to an encrypted journal entry, refunding the grant's rent.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// A reader who already unwrapped the key keeps it; rewrite the entry under a new key to
+    /// stop them from reading later versions.
+    pub fn revoke_access(ctx: Context<RevokeAccess>) -> Result<()> {
+        instructions::revoke_access::handler(ctx)
+    }
 
-        // Access the mutable reference to the journal entry account.
-        let journal_entry = &mut ctx.accounts.journal_entry;
-        // Set the owner of the journal entry to the public key of the transaction signer.
-        journal_entry.owner = ctx.accounts.owner.key();
-        // Set the title and message of the journal entry.
-        journal_entry.title = title;
-        journal_entry.message = message;
-        Ok(())
+    /// Creates a new journal entry whose message is encrypted by the client.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `title` - The title of the journal entry, which stays public.
+    /// * `message_ciphertext` - The encrypted message.
+    /// * `encryption` - The nonce and public key needed to decrypt the message.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// The program stores the ciphertext as raw bytes and never checks that it decrypts. The
+    /// header must name the current version of the owner's key registry.
+    pub fn create_encrypted_entry(
+        ctx: Context<CreateEncryptedEntry>,
+        title: String,
+        message_ciphertext: Vec<u8>,
+        encryption: EncryptionHeader,
+    ) -> Result<()> {
+        instructions::create_encrypted_entry::handler(ctx, title, message_ciphertext, encryption)
     }
 
     /// Updates an existing journal entry.
@@ -53,148 +342,1388 @@ pub mod journal {
     /// * `ctx` - The context containing the accounts involved in the transaction.
     /// * `title` - The new title of the journal entry.
     /// * `message` - The new message of the journal entry.
+    /// * `expected_version` - The entry version the caller read before editing.
     ///
     /// # Returns
     ///
     /// * `Result<()>` - Returns an empty result on success.
     ///
-    /// This function updates the message of an existing journal entry account with the provided message.
+    /// This function replaces the title and message of an existing journal entry account. The
+    /// entry's address does not depend on its title, so retitling keeps the same account.
+    /// If a snapshot account is supplied, the previous content is copied into it first.
+    /// If the entry was written since `expected_version` was read, for example from another
+    /// device, the update fails with `StaleVersion` instead of clobbering that write.
     /// It logs the update of the entry.
     pub fn update_journal_entry(
         ctx: Context<UpdateEntry>,
         title: String,
         message: String,
+        expected_version: u64,
     ) -> Result<()> {
-        // Log messages to the Solana runtime, useful for debugging.
-        msg!("Journal Entry Updated");
-        msg!("Title: {}", title);
-        msg!("Message: {}", message);
+        instructions::update_journal_entry::handler(ctx, title, message, expected_version)
+    }
+
+    /// Replaces the message of an existing journal entry, leaving its title unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `message` - The new message of the journal entry.
+    /// * `expected_version` - The entry version the caller read before editing.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Like `update_journal_entry`, fails with `StaleVersion` if the entry changed since it was
+    /// read. Use `rename_journal_entry` to change only the title, so neither field has to be resent
+    /// when editing the other. If a snapshot account is supplied, the previous content is copied
+    /// into it first.
+    pub fn update_message(
+        ctx: Context<UpdateMessage>,
+        message: String,
+        expected_version: u64,
+    ) -> Result<()> {
+        instructions::update_message::handler(ctx, message, expected_version)
+    }
+
+    /// Replaces the message of an existing journal entry with an encrypted one.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `message_ciphertext` - The new encrypted message.
+    /// * `encryption` - The nonce and public key needed to decrypt the message.
+    /// * `expected_version` - The entry version the caller read before editing.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Also encrypts a plaintext entry, dropping its message. Writing a plaintext message with
+    /// `update_message` decrypts it again. Encrypted entries cannot be appended to or restored
+    /// from their revision history. Revisioned entries record the plaintext message being
+    /// emptied, so the history stays continuous.
+    pub fn update_encrypted_message(
+        ctx: Context<UpdateEncryptedMessage>,
+        message_ciphertext: Vec<u8>,
+        encryption: EncryptionHeader,
+        expected_version: u64,
+    ) -> Result<()> {
+        instructions::update_encrypted_message::handler(
+            ctx,
+            message_ciphertext,
+            encryption,
+            expected_version,
+        )
+    }
 
-        // Access the mutable reference to the journal entry account.
-        let journal_entry = &mut ctx.accounts.journal_entry;
-        // Update the message of the journal entry.
-        journal_entry.message = message;
+    /// Appends text to the message of an existing journal entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `extra_text` - The text to add to the end of the message.
+    /// * `expected_version` - The entry version the caller read before appending.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// This function grows the entry by the appended text, so a running log can be kept without
+    /// resending the whole message. The combined message must still fit the maximum length. Like
+    /// `update_message`, fails with `StaleVersion` if the entry changed since it was read.
+    pub fn append_journal_entry(
+        ctx: Context<AppendEntry>,
+        extra_text: String,
+        expected_version: u64,
+    ) -> Result<()> {
+        instructions::append_journal_entry::handler(ctx, extra_text, expected_version)
+    }
 
-        Ok(())
+    /// Renames an existing journal entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `old_title` - The entry's current title, guarding against renaming a stale copy.
+    /// * `new_title` - The new title of the journal entry.
+    /// * `expected_version` - The entry version the caller read before renaming.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Entry addresses are derived from their index rather than their title, so the entry is
+    /// renamed in place and keeps its address, message and history. Like `update_message`, fails
+    /// with `StaleVersion` if the entry changed since it was read.
+    pub fn rename_journal_entry(
+        ctx: Context<RenameEntry>,
+        old_title: String,
+        new_title: String,
+        expected_version: u64,
+    ) -> Result<()> {
+        instructions::rename_journal_entry::handler(ctx, old_title, new_title, expected_version)
     }
 
-    /// Deletes an existing journal entry.
+    /// Seals a journal entry, making it permanently immutable.
     ///
     /// # Arguments
     ///
     /// * `ctx` - The context containing the accounts involved in the transaction.
-    /// * `title` - The title of the journal entry to be deleted.
     ///
     /// # Returns
     ///
     /// * `Result<()>` - Returns an empty result on success.
     ///
-    /// This function deletes an existing journal entry account.
-    /// It logs the deletion of the entry.
-    #[allow(unused_variables)]
-    pub fn delete_journal_entry(ctx: Context<DeleteEntry>, title: String) -> Result<()> {
-        // Log the deletion message to the Solana runtime, useful for debugging.
-        msg!("Journal entry titled {} deleted", title);
+    /// Sealing cannot be undone: afterwards every update, rename, append, revision restore and
+    /// deletion of the entry fails with `SealedEntry`, including ones signed by the owner.
+    pub fn seal_journal_entry(ctx: Context<SealEntry>) -> Result<()> {
+        instructions::seal_journal_entry::handler(ctx)
+    }
 
-        Ok(())
+    /// Limits how long after creation a journal entry can be edited.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `hours` - The number of hours after creation during which edits are accepted.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Once the window closes, content edits fail with `EditWindowClosed`. A window can later be
+    /// shortened but never extended or removed, so the discipline cannot be undone by the owner.
+    pub fn set_edit_window(ctx: Context<SetEditWindow>, hours: u32) -> Result<()> {
+        instructions::set_edit_window::handler(ctx, hours)
     }
-}
 
-/// Represents the state of a journal entry.
-/// The `#[account]` attribute macro defines a struct that will be stored on-chain.
-/// The `#[derive(InitSpace)]` attribute macro is used to initialize the account with a space of 8 bytes.
-#[account]
-#[derive(InitSpace)]
-pub struct JournalEntryState {
-    /// The public key of the owner of the journal entry.
-    pub owner: Pubkey,
-    /// The title of the journal entry. Maximum length is 50 characters.
-    #[max_len(50)]
-    pub title: String,
-    /// The message of the journal entry. Maximum length is 1000 characters.
-    #[max_len(1000)]
-    pub message: String,
-}
+    /// Replaces the tags of a journal entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `tags` - Up to `MAX_TAGS` topics of at most `MAX_TAG_LENGTH` bytes each.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Tags are metadata rather than content, so changing them does not bump the entry's version.
+    pub fn set_tags(ctx: Context<SetTags>, tags: Vec<String>) -> Result<()> {
+        instructions::set_tags::handler(ctx, tags)
+    }
 
-/// The context for the `create_journal_entry` function.
-/// The `#[derive(Accounts)]` attribute macro defines the accounts required for the function.
-#[derive(Accounts)]
-#[instruction(title: String, message: String)]
-pub struct CreateEntry<'info> {
-    /// The account to be created or initialized for the journal entry.
-    ///
-    /// - `init_if_needed`: Initializes the account if it doesn't already exist.
-    /// - `seeds`: A unique identifier for the account, derived from the title and owner's public key.
-    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
-    /// - `payer`: The account that will pay for the account creation.
-    /// - `space`: The amount of space to allocate for the account.
-    #[account(
-        init_if_needed,
-        seeds = [title.as_bytes(), owner.key().as_ref()],
-        bump,
-        payer = owner,
-        space = 8 + JournalEntryState::INIT_SPACE
-    )]
-    pub journal_entry: Account<'info, JournalEntryState>,
-    /// The signer of the transaction.
-    /// This account must sign the transaction to authorize it.
-    #[account(mut)]
-    pub owner: Signer<'info>,
-    /// The system program required for account creation.
-    /// This is a built-in program that provides basic account management functionalities.
-    pub system_program: Program<'info, System>,
-}
+    /// Changes the category of a journal entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `category` - The new category of the entry.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Entries created from permits, proposals or delegates start as `Personal`.
+    pub fn set_category(ctx: Context<SetCategory>, category: EntryCategory) -> Result<()> {
+        instructions::set_category::handler(ctx, category)
+    }
 
-#[derive(Accounts)]
-#[instruction(title: String, message: String)]
-pub struct UpdateEntry<'info> {
-    /// The account to be updated for the journal entry.
-    ///
-    /// - `mut`: The account is mutable, meaning it can be modified.
-    /// - `seeds`: A unique identifier for the account, derived from the title and owner's public key.
-    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
-    /// - `realloc`: Reallocates the account with the new size.
-    /// - `realloc::payer`: The account that will pay for the reallocation.
-    /// - `realloc::zero`: Ensures the newly allocated space is zeroed out.
-    #[account(
-        mut,
-        seeds = [title.as_bytes(), owner.key().as_ref()],
-        bump,
-        realloc = 8 + 32 + 1 + 4 + title.len() + 4 + message.len(),
-        realloc::payer = owner,
-        realloc::zero = true,
-    )]
-    pub journal_entry: Account<'info, JournalEntryState>,
-    /// The signer of the transaction.
-    /// This account must sign the transaction to authorize it.
-    #[account(mut)]
-    pub owner: Signer<'info>,
-    /// The system program required for account reallocation.
-    /// This is a built-in program that provides basic account management functionalities.
-    pub system_program: Program<'info, System>,
-}
+    /// Records the mood of a journal entry and updates the owner's mood statistics.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `mood` - How the owner felt, or `None` to clear it.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Send it alongside `create_journal_entry` or an update to record the mood at writing
+    /// time. Changing an entry's mood moves its count in the statistics rather than adding one.
+    pub fn set_mood(ctx: Context<SetMood>, mood: Option<Mood>) -> Result<()> {
+        instructions::set_mood::handler(ctx, mood)
+    }
 
-#[derive(Accounts)]
-#[instruction(title: String)]
-pub struct DeleteEntry<'info> {
-    /// The account to be deleted for the journal entry.
-    ///
-    /// - `mut`: The account is mutable, meaning it can be modified.
-    /// - `seeds`: A unique identifier for the account, derived from the title and owner's public key.
-    /// - `bump`: A nonce used to ensure the uniqueness of the derived address.
-    /// - `close`: Closes the account and transfers the remaining lamports to the specified account.
-    #[account(
-        mut,
-        seeds = [title.as_bytes(), owner.key().as_ref()],
-        bump,
-        close = owner,
-    )]
-    pub journal_entry: Account<'info, JournalEntryState>,
-    /// The signer of the transaction.
-    /// This account must sign the transaction to authorize it.
-    #[account(mut)]
-    pub owner: Signer<'info>,
-    /// The system program required for account closure.
-    /// This is a built-in program that provides basic account management functionalities.
-    pub system_program: Program<'info, System>,
+    /// Records where a journal entry was written.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `lat` - The latitude in micro-degrees, between -90,000,000 and 90,000,000.
+    /// * `lng` - The longitude in micro-degrees, between -180,000,000 and 180,000,000.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Pass both coordinates to set the location or neither to clear it; anything else fails
+    /// with `InvalidLocation`.
+    pub fn set_location(
+        ctx: Context<SetLocation>,
+        lat: Option<i32>,
+        lng: Option<i32>,
+    ) -> Result<()> {
+        instructions::set_location::handler(ctx, lat, lng)
+    }
+
+    /// Changes how a journal entry's message should be rendered.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `content_type` - The new content type of the entry.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Switching to `Json` fails with `InvalidJson` unless the current message is well-formed.
+    /// Every later write of a JSON entry's message is checked the same way.
+    pub fn set_content_type(ctx: Context<SetContentType>, content_type: ContentType) -> Result<()> {
+        instructions::set_content_type::handler(ctx, content_type)
+    }
+
+    /// Changes the language of a journal entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `lang` - The lowercase ISO 639-1 code of the language, or zeros to leave it unspecified.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    pub fn set_language(ctx: Context<SetLanguage>, lang: [u8; 2]) -> Result<()> {
+        instructions::set_language::handler(ctx, lang)
+    }
+
+    /// Attaches a file stored on IPFS or Arweave to a journal entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `cid` - The content identifier of the file.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// An entry holds at most `MAX_ATTACHMENTS` attachments. Attachments are part of the entry's
+    /// content, so sealed, locked or trashed entries and closed edit windows reject them.
+    pub fn add_attachment(ctx: Context<AddAttachment>, cid: String) -> Result<()> {
+        instructions::add_attachment::handler(ctx, cid)
+    }
+
+    /// Removes an attachment from a journal entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `cid` - The content identifier of the attachment to remove.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    pub fn remove_attachment(ctx: Context<RemoveAttachment>, cid: String) -> Result<()> {
+        instructions::remove_attachment::handler(ctx, cid)
+    }
+
+    /// Sets or clears the cover image of a journal entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `cover_cid` - The IPFS or Arweave content identifier of the image, or `None` to clear it.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Like attachments, the cover is part of the entry's content and is validated the same way.
+    pub fn set_cover(ctx: Context<SetCover>, cover_cid: Option<String>) -> Result<()> {
+        instructions::set_cover::handler(ctx, cover_cid)
+    }
+
+    /// Publishes a draft journal entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Drafts can be edited like any other entry, but views and attestations are rejected with
+    /// `EntryNotPublished` until the entry is published, and they are only listed on the
+    /// author's entry index page once published. Publishing cannot be undone. Entries scheduled
+    /// with `publish_at` fail with `TooEarly` until that time.
+    pub fn publish_entry(ctx: Context<PublishEntry>) -> Result<()> {
+        instructions::publish_entry::handler(ctx)
+    }
+
+    /// Locks a journal entry as a time capsule until a future date.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `unlock_at` - The Unix timestamp until which the entry stays locked.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Until `unlock_at`, content edits and deletion fail with `EntryLocked`, even for the owner.
+    /// A lock can be extended but never shortened or removed. `unlock_at` must be in the future,
+    /// otherwise `InvalidUnlockTime` is returned.
+    pub fn lock_entry(ctx: Context<LockEntry>, unlock_at: i64) -> Result<()> {
+        instructions::lock_entry::handler(ctx, unlock_at)
+    }
+
+    /// Records a sign of life from the owner, resetting their dead man's switch.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Creating, editing, deleting and restoring one's own entries records a heartbeat too, so
+    /// an owner who keeps writing never needs to send one.
+    pub fn heartbeat(ctx: Context<Heartbeat>) -> Result<()> {
+        instructions::heartbeat::handler(ctx)
+    }
+
+    /// Configures how long the owner may go without a heartbeat before their drafts are released.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `release_after` - The delay in seconds, or `None` to disarm the switch.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Setting the delay also records a heartbeat.
+    pub fn set_release_delay(
+        ctx: Context<SetReleaseDelay>,
+        release_after: Option<i64>,
+    ) -> Result<()> {
+        instructions::set_release_delay::handler(ctx, release_after)
+    }
+
+    /// Publishes the draft entries of an owner who has stopped recording heartbeats.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction. The owner's
+    ///   entries to release are passed as writable remaining accounts, each entry that is
+    ///   released followed by its audit log if it is audited, then by its entry index page.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Anyone may call this once the owner's release delay has passed since their last
+    /// heartbeat; before that it fails with `OwnerStillActive`. Writing to their own entries
+    /// counts as a heartbeat. Published and trashed entries are left unchanged, and drafts
+    /// scheduled with `publish_at` are only released once that time has come.
+    pub fn release_entries<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReleaseEntries<'info>>,
+    ) -> Result<()> {
+        instructions::release_entries::handler(ctx)
+    }
+
+    /// Authorizes a delegate key to write to the owner's journal until an expiry.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `delegate` - The key allowed to write on the owner's behalf, e.g. a mobile session key.
+    /// * `scope` - Whether the delegate may create entries, update them, or both.
+    /// * `expires_at` - The Unix timestamp after which the delegation is no longer valid.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Calling this again for the same delegate replaces the scope and expiry.
+    pub fn set_write_delegate(
+        ctx: Context<SetWriteDelegate>,
+        delegate: Pubkey,
+        scope: DelegateScope,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::set_write_delegate::handler(ctx, delegate, scope, expires_at)
+    }
+
+    /// Revokes a write delegation, returning its rent to the owner.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    pub fn revoke_write_delegate(ctx: Context<RevokeWriteDelegate>) -> Result<()> {
+        instructions::revoke_write_delegate::handler(ctx)
+    }
+
+    /// Creates a journal entry on the owner's behalf, signed by a write delegate.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `title` - The title of the journal entry.
+    /// * `message` - The message of the journal entry.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// The delegate pays rent and fees; the owner does not sign. Fails with `DelegateExpired` or
+    /// `DelegateOutOfScope` unless the delegation is current and allows creating entries.
+    pub fn delegate_create_entry(
+        ctx: Context<DelegateCreateEntry>,
+        title: String,
+        message: String,
+    ) -> Result<()> {
+        instructions::delegate_create_entry::handler(ctx, title, message)
+    }
+
+    /// Replaces the message of one of the owner's entries, signed by a write delegate.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `message` - The new message of the journal entry.
+    /// * `expected_version` - The entry version the delegate read before editing.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Behaves like `update_message`, with the delegate paying for any reallocation and recorded
+    /// as the actor in the audit log. Lamports freed by a shorter message go to the owner. The delegation must be current and allow updates.
+    pub fn delegate_update_message(
+        ctx: Context<DelegateUpdateMessage>,
+        message: String,
+        expected_version: u64,
+    ) -> Result<()> {
+        instructions::delegate_update_message::handler(ctx, message, expected_version)
+    }
+
+    /// Offers a journal entry to a new owner.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `new_owner` - The wallet the entry is offered to.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Ownership only moves once `new_owner` calls `accept_transfer`, so a mistyped address
+    /// never receives the entry. Offering the entry again replaces the pending offer. Offering a
+    /// protected entry requires its guardian's co-signature.
+    pub fn initiate_transfer(ctx: Context<InitiateTransfer>, new_owner: Pubkey) -> Result<()> {
+        instructions::initiate_transfer::handler(ctx, new_owner)
+    }
+
+    /// Accepts a pending transfer of a journal entry, making the signer its owner.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Fails with `NotPendingOwner` unless the entry was offered to the signer. The entry keeps
+    /// its address, which derives from its author. The change is recorded in the entry's audit
+    /// log, if it has one.
+    pub fn accept_transfer(ctx: Context<AcceptTransfer>) -> Result<()> {
+        instructions::accept_transfer::handler(ctx)
+    }
+
+    /// Transfers a batch of the signer's entries to a new wallet.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction. The entries to
+    ///   migrate are passed as writable remaining accounts.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Both the old and the new wallet sign, so no acceptance step is needed. A large journal is
+    /// migrated over several transactions, each listing as many entries as fit. Audited entries
+    /// fail with `AuditLogRequired` and are transferred one by one, so the change is recorded.
+    pub fn migrate_journal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MigrateJournal<'info>>,
+    ) -> Result<()> {
+        instructions::migrate_journal::handler(ctx)
+    }
+
+    /// Names the wallet that may inherit the owner's entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `beneficiary` - The heir, or `None` to remove it.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Setting the beneficiary also records a heartbeat.
+    pub fn set_beneficiary(
+        ctx: Context<SetBeneficiary>,
+        beneficiary: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_beneficiary::handler(ctx, beneficiary)
+    }
+
+    /// Transfers an inactive owner's entries to their beneficiary.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction. The owner's
+    ///   entries to claim are passed as writable remaining accounts, each followed by its audit
+    ///   log if it is audited, then by its journal if it is filed in one.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Trashed entries can't be claimed. Claimed entries are taken out of the previous owner's
+    /// journals, and audited ones record the transfer.
+    ///
+    /// The beneficiary can claim once the owner's release delay has passed since their last
+    /// heartbeat; before that it fails with `OwnerStillActive`. Writing to their own entries
+    /// counts as a heartbeat. Entries keep their addresses, which derive from their author
+    /// rather than their owner.
+    pub fn claim_inherited_journal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimInheritedJournal<'info>>,
+    ) -> Result<()> {
+        instructions::claim_inherited_journal::handler(ctx)
+    }
+
+    /// Archives a journal entry, hiding it from the owner's feed.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Unlike deletion, archiving keeps the account and its content, and can be undone with
+    /// `unarchive_entry`.
+    pub fn archive_entry(ctx: Context<SetArchived>) -> Result<()> {
+        instructions::set_archived::handler(ctx, true)
+    }
+
+    /// Restores an archived journal entry to the owner's feed.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    pub fn unarchive_entry(ctx: Context<SetArchived>) -> Result<()> {
+        instructions::set_archived::handler(ctx, false)
+    }
+
+    /// Deletes an existing journal entry by moving it to the trash.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// The entry keeps its account and content but can no longer be edited. It can be brought
+    /// back with `restore_entry` during the configured grace period and closed with
+    /// `purge_entry` after it. Protected entries additionally require their guardian's
+    /// co-signature. It logs the deletion of the entry.
+    pub fn delete_journal_entry(ctx: Context<DeleteEntry>) -> Result<()> {
+        instructions::delete_journal_entry::handler(ctx)
+    }
+
+    /// Restores a journal entry from the trash.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// This function only succeeds within the grace period that started when the entry was
+    /// deleted.
+    pub fn restore_entry(ctx: Context<RestoreEntry>) -> Result<()> {
+        instructions::restore_entry::handler(ctx)
+    }
+
+    /// Permanently closes a journal entry that has been in the trash for the grace period.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// This function closes the entry account and sends its lamports to the rent recipient chosen
+    /// by the owner. Entries filed in a named journal must be passed with it. The entry is
+    /// unlinked from its author's entry list, whose neighboring entries must be passed too, and
+    /// removed from its entry index page. Its audit log and revision history are closed with it.
+    ///
+    /// The entry's view records, attestations, access grants and snapshots can be passed as
+    /// remaining accounts, each followed by the wallet that paid for it, which gets its rent back.
+    /// View records have no other way to be closed, so the entry is only closed once all of them
+    /// are; until then each call closes the records it is passed and leaves the entry in the trash.
+    pub fn purge_entry<'info>(ctx: Context<'_, '_, 'info, 'info, PurgeEntry<'info>>) -> Result<()> {
+        instructions::purge_entry::handler(ctx)
+    }
+
+    /// Proposes a journal entry for another wallet's journal.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `title` - The title of the proposed entry.
+    /// * `message` - The message of the proposed entry.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// The proposal is held in a pending entry, paid for by the proposer, until the journal
+    /// owner approves or rejects it. Nothing is written to the owner's journal until approval.
+    pub fn propose_entry(ctx: Context<ProposeEntry>, title: String, message: String) -> Result<()> {
+        instructions::propose_entry::handler(ctx, title, message)
+    }
+
+    /// Approves a pending entry, adding it to the owner's journal.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// The owner pays for the new journal entry, added at the next index of their journal, and
+    /// the pending entry's rent is refunded to the proposer.
+    pub fn approve_entry(ctx: Context<ApproveEntry>) -> Result<()> {
+        instructions::approve_entry::handler(ctx)
+    }
+
+    /// Rejects a pending entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// This function closes the pending entry and refunds its rent to the proposer.
+    pub fn reject_entry(ctx: Context<RejectEntry>) -> Result<()> {
+        instructions::reject_entry::handler(ctx)
+    }
+
+    /// Sets or clears the guardian of a journal entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `guardian` - The new guardian, or `None` to remove protection.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// A protected entry can only be deleted or transferred with its guardian's co-signature.
+    /// Changing or removing an existing guardian also requires that guardian to sign, so
+    /// protection cannot be dropped unilaterally by the owner.
+    pub fn set_entry_guardian(
+        ctx: Context<SetEntryGuardian>,
+        guardian: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_entry_guardian::handler(ctx, guardian)
+    }
+
+    /// Enables the append-only audit log for a journal entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Once enabled, auditing cannot be turned off: every later change to the entry, including
+    /// its metadata, settings, ownership and lifecycle, must pass the audit log, which records the
+    /// actor, the action, the time and the resulting content hash.
+    pub fn enable_audit_log(ctx: Context<EnableAuditLog>) -> Result<()> {
+        instructions::enable_audit_log::handler(ctx)
+    }
+
+    /// Enables revision history for a journal entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `depth` - The number of revisions to retain, from 1 to 8.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Once enabled, every update of the entry stores a compact diff of the replaced text and the
+    /// prior content hash, so that accidental overwrites can be undone with `restore_revision`.
+    pub fn enable_revisions(ctx: Context<EnableRevisions>, depth: u8) -> Result<()> {
+        instructions::enable_revisions::handler(ctx, depth)
+    }
+
+    /// Closes snapshots of journal entries, returning their rent to the owner.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction. The snapshots
+    ///   to close are passed as remaining accounts.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Snapshots are written by `update_journal_entry` and `update_message` when a snapshot
+    /// account is supplied. Every snapshot must belong to the signer, otherwise `NotOwner` is
+    /// returned. Snapshots can be pruned even after their entry has been purged.
+    pub fn prune_revisions<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PruneRevisions<'info>>,
+    ) -> Result<()> {
+        instructions::prune_revisions::handler(ctx)
+    }
+
+    /// Restores a journal entry's message to its value before the most recent update.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// This function consumes the most recent revision, so calling it repeatedly walks back
+    /// through the retained history. The restored content must match the revision's prior hash.
+    pub fn restore_revision(ctx: Context<RestoreRevision>) -> Result<()> {
+        instructions::restore_revision::handler(ctx)
+    }
+
+    /// Sets the ordered table of contents for one of the owner's journals.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `items` - The ordered list of section headers and entry references.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// This function creates the table of contents on first use and replaces its items on every
+    /// call, letting public journals render in an author-chosen order. Each named journal has its
+    /// own table, and the owner's default journal has one too. The referenced entries are passed
+    /// in order as remaining accounts and must be the owner's published entries filed in that
+    /// journal; drafts are rejected with `EntryNotPublished`.
+    pub fn set_toc_order<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SetTocOrder<'info>>,
+        items: Vec<TocItem>,
+    ) -> Result<()> {
+        instructions::set_toc_order::handler(ctx, items)
+    }
+
+    /// Records a view of a journal entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// This function creates a view record for the (viewer, entry) pair the first time the viewer
+    /// reads the entry and only then increments the entry's view count. Repeated views by the same
+    /// viewer succeed but are not counted again, so the counter cannot be inflated by one wallet.
+    /// Drafts cannot be viewed.
+    pub fn record_view(ctx: Context<RecordView>) -> Result<()> {
+        instructions::record_view::handler(ctx)
+    }
+
+    /// Creates a journal owned jointly by several wallets.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `id` - The creator's identifier for the journal, which derives its address.
+    /// * `owners` - Up to `MAX_MULTISIG_OWNERS` distinct owners.
+    /// * `threshold` - The number of owners that must approve each change.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Entries of the journal are owned by the multisig account and can only be changed through
+    /// approved proposals, not by the single-owner instructions.
+    pub fn create_multisig_journal(
+        ctx: Context<CreateMultisigJournal>,
+        id: u64,
+        owners: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::create_multisig_journal::handler(ctx, id, owners, threshold)
+    }
+
+    /// Proposes creating, updating or deleting an entry of a multisig journal.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `action` - The change to make.
+    /// * `entry` - The entry to update or delete, or `None` when creating one.
+    /// * `expected_version` - The entry version the proposal is made against, or zero when
+    ///   creating one.
+    /// * `title` - The new title, or empty when deleting.
+    /// * `message` - The new message, or empty when deleting.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Only owners can propose, and the proposer's approval is counted immediately. The proposal
+    /// expires `MULTISIG_PROPOSAL_LIFETIME` seconds later, and fails with `StaleVersion` if the
+    /// entry is written before it is executed.
+    pub fn propose_multisig_action(
+        ctx: Context<ProposeMultisigAction>,
+        action: MultisigAction,
+        entry: Option<Pubkey>,
+        expected_version: u64,
+        title: String,
+        message: String,
+    ) -> Result<()> {
+        instructions::propose_multisig_action::handler(
+            ctx,
+            action,
+            entry,
+            expected_version,
+            title,
+            message,
+        )
+    }
+
+    /// Approves a multisig proposal as one of the journal's owners.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Approving twice has no further effect. Expired proposals can no longer be approved.
+    pub fn approve_multisig_action(ctx: Context<ApproveMultisigAction>) -> Result<()> {
+        instructions::approve_multisig_action::handler(ctx)
+    }
+
+    /// Cancels a multisig proposal, refunding its rent to the proposer.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// The proposer can withdraw a proposal at any time, and any owner can clear it once it has
+    /// expired.
+    pub fn cancel_multisig_proposal(ctx: Context<CancelMultisigProposal>) -> Result<()> {
+        instructions::cancel_multisig_proposal::handler(ctx)
+    }
+
+    /// Executes an approved proposal to create a multisig journal entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Any owner may execute once the threshold is met. The executor pays for the entry and the
+    /// proposal's rent is refunded to its proposer.
+    pub fn execute_multisig_create(ctx: Context<ExecuteMultisigCreate>) -> Result<()> {
+        instructions::execute_multisig_create::handler(ctx)
+    }
+
+    /// Executes an approved proposal to update a multisig journal entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Like `execute_multisig_create`, but replaces the entry's title and message.
+    pub fn execute_multisig_update(ctx: Context<ExecuteMultisigUpdate>) -> Result<()> {
+        instructions::execute_multisig_update::handler(ctx)
+    }
+
+    /// Executes an approved proposal to delete a multisig journal entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Like `delete_journal_entry`, the entry is moved to the trash, and sealed, locked or
+    /// guarded entries cannot be deleted. Multisig journals have no restore, so executing another
+    /// deletion once the trash grace period has passed purges the entry, refunding its rent and
+    /// that of its audit log and revision history to the executor. As with `purge_entry`, the
+    /// entry's companion accounts are passed as remaining accounts, each followed by its payer,
+    /// and the purge fails with `ViewRecordsOpen` unless every view record is among them.
+    pub fn execute_multisig_delete<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteMultisigDelete<'info>>,
+    ) -> Result<()> {
+        instructions::execute_multisig_delete::handler(ctx)
+    }
+
+    /// Creates a named journal to file entries in.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `name` - The journal's name, unique per owner, of at most `MAX_JOURNAL_NAME_LENGTH` bytes.
+    /// * `description` - What the journal is for.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Entries are filed in it by passing it to `create_journal_entry`.
+    pub fn create_journal(
+        ctx: Context<CreateJournal>,
+        name: String,
+        description: String,
+    ) -> Result<()> {
+        instructions::create_journal::handler(ctx, name, description)
+    }
+
+    /// Moves a page of a named journal's entries to the trash, and closes the journal once it is
+    /// empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// The entries are passed as writable remaining accounts and must all be filed in the
+    /// journal, each audited entry followed by its audit log. They are moved to their owner's
+    /// default journal and, unless sealed, locked, guarded, or written or owned by someone else,
+    /// to the trash, exactly as `delete_journal_entry` would. Closing them outright would require
+    /// unlinking each from its author's entry list, so they are purged one by one afterwards. The
+    /// journal's rent is refunded to the owner once its last entry is gone. Large journals are
+    /// closed over several calls.
+    pub fn close_journal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseJournal<'info>>,
+    ) -> Result<()> {
+        instructions::close_journal::handler(ctx)
+    }
+
+    /// Moves a journal entry to another of the owner's journals.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Omitting the destination moves the entry to the owner's default journal. Both journals'
+    /// entry counts are updated. A transferred entry can also be moved out of its previous
+    /// owner's journal this way.
+    pub fn move_entry(ctx: Context<MoveEntry>) -> Result<()> {
+        instructions::move_entry::handler(ctx)
+    }
+
+    /// Creates a journal that several members write to, with the creator as its first admin.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `id` - The creator's identifier for the journal, which derives its address.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    pub fn create_shared_journal(ctx: Context<CreateSharedJournal>, id: u64) -> Result<()> {
+        instructions::create_shared_journal::handler(ctx, id)
+    }
+
+    /// Invites a wallet to join a shared journal.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `invitee` - The public key of the wallet being invited.
+    /// * `role` - What the invitee may do once they join.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Only admins can invite, and they pay for the invitation until it is accepted or
+    /// cancelled. Membership starts only when the invitee accepts.
+    pub fn invite_member(
+        ctx: Context<InviteMember>,
+        invitee: Pubkey,
+        role: MemberRole,
+    ) -> Result<()> {
+        instructions::invite_member::handler(ctx, invitee, role)
+    }
+
+    /// Accepts an invitation to a shared journal, making the invitee a member.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// The invitee pays for their membership and the invitation's rent returns to the inviter.
+    pub fn accept_invitation(ctx: Context<AcceptInvitation>) -> Result<()> {
+        instructions::accept_invitation::handler(ctx)
+    }
+
+    /// Cancels a pending invitation, refunding its rent to the inviter.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Either the inviter or the invitee, declining it, may cancel.
+    pub fn cancel_invitation(ctx: Context<CancelInvitation>) -> Result<()> {
+        instructions::cancel_invitation::handler(ctx)
+    }
+
+    /// Changes the role of a shared journal member.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `role` - The member's new role.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Only admins can change roles, and not their own.
+    pub fn set_member_role(ctx: Context<SetMemberRole>, role: MemberRole) -> Result<()> {
+        instructions::set_member_role::handler(ctx, role)
+    }
+
+    /// Removes a member from a shared journal, refunding their membership's rent to them.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `policy` - What happens to the member's entries.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Only admins can remove members, and not themselves. Unless the entries are retained, the
+    /// member's entries are passed as writable remaining accounts, each audited entry followed by
+    /// its audit log, and are either sealed or transferred to the removing admin. The membership
+    /// counts the member's entries, and is only closed once every one of them has been settled;
+    /// until then the member is demoted to reader and the removal continues over further calls.
+    pub fn remove_member<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RemoveMember<'info>>,
+        policy: RetentionPolicy,
+    ) -> Result<()> {
+        instructions::remove_member::handler(ctx, policy)
+    }
+
+    /// Creates an entry in a shared journal.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `title` - The title of the journal entry.
+    /// * `message` - The message of the journal entry.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// The author must be a writer or admin. The entry is owned by the shared journal and
+    /// derived from the author's own entry index, so its author is recorded for access checks.
+    pub fn create_shared_entry(
+        ctx: Context<CreateSharedEntry>,
+        title: String,
+        message: String,
+    ) -> Result<()> {
+        instructions::create_shared_entry::handler(ctx, title, message)
+    }
+
+    /// Updates an entry of a shared journal.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `title` - The new title of the journal entry.
+    /// * `message` - The new message of the journal entry.
+    /// * `expected_version` - The entry version the caller read before editing.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Admins may update any entry of the journal; writers only the entries they authored.
+    pub fn update_shared_entry(
+        ctx: Context<UpdateSharedEntry>,
+        title: String,
+        message: String,
+        expected_version: u64,
+    ) -> Result<()> {
+        instructions::update_shared_entry::handler(ctx, title, message, expected_version)
+    }
+
+    /// Deletes an entry of a shared journal, refunding its rent to its author.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Follows the same access rules as `update_shared_entry`. The entry is unlinked from its
+    /// author's entry list, whose neighboring entries must be passed too, and removed from its
+    /// entry index page.
+    pub fn delete_shared_entry(ctx: Context<DeleteSharedEntry>) -> Result<()> {
+        instructions::delete_shared_entry::handler(ctx)
+    }
+
+    /// Sets or clears the witness asked to co-sign a journal entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `witness` - The new witness, or `None` to remove it.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Any signature by the previous witness is cleared.
+    pub fn set_witness(ctx: Context<SetWitness>, witness: Option<Pubkey>) -> Result<()> {
+        instructions::set_witness::handler(ctx, witness)
+    }
+
+    /// Co-signs the content of a journal entry as its witness.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `content_hash` - The hash of the entry's title and body, as read by the witness.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Fails with `ContentHashMismatch` if the entry changed since the witness read it. Later
+    /// edits leave the signed hash in place, so readers can tell the content has moved on.
+    pub fn witness_entry(ctx: Context<WitnessEntry>, content_hash: [u8; 32]) -> Result<()> {
+        instructions::witness_entry::handler(ctx, content_hash)
+    }
+
+    /// Notarizes an external document by recording its hash.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `hash` - The hash of the document being notarized.
+    /// * `algorithm` - The algorithm used to compute the hash.
+    /// * `label` - A short, human-readable label for the document.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// This function creates a hash-only notarization record that proves the document existed at
+    /// the current time. No document content is stored on-chain.
+    pub fn notarize_document(
+        ctx: Context<NotarizeDocument>,
+        hash: [u8; 32],
+        algorithm: HashAlgorithm,
+        label: String,
+    ) -> Result<()> {
+        instructions::notarize_document::handler(ctx, hash, algorithm, label)
+    }
+
+    /// Publishes a prompt pack for sale.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `name` - The name of the pack, unique per creator.
+    /// * `price` - The price of the pack, in lamports. Zero makes the pack free.
+    /// * `prompts` - The journaling prompts in the pack.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    pub fn publish_prompt_pack(
+        ctx: Context<PublishPromptPack>,
+        name: String,
+        price: u64,
+        prompts: Vec<String>,
+    ) -> Result<()> {
+        instructions::publish_prompt_pack::handler(ctx, name, price, prompts)
+    }
+
+    /// Buys a prompt pack, granting the buyer access to its prompts.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// The price is split between the pack's creator and the treasury according to the
+    /// configured fee. The buyer receives an access account proving the purchase, which
+    /// `create_journal_entry` requires to create entries answering the pack's prompts.
+    pub fn buy_prompt_pack(ctx: Context<BuyPromptPack>) -> Result<()> {
+        instructions::buy_prompt_pack::handler(ctx)
+    }
+
+    /// Claims a vanity slug pointing at a journal account.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `slug` - The slug to claim: lowercase letters, digits and inner hyphens.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// Each slug can be held by one owner at a time, and resolves to the `target` account: one
+    /// of the owner's profile, journals or tables of contents. The configured claim fee, if any,
+    /// is paid to the treasury.
+    pub fn claim_slug(ctx: Context<ClaimSlug>, slug: String) -> Result<()> {
+        instructions::claim_slug::handler(ctx, slug)
+    }
+
+    /// Releases a slug so that it can be claimed again.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// This function closes the slug account and refunds its rent to the owner. The claim fee
+    /// is not refunded.
+    pub fn release_slug(ctx: Context<ReleaseSlug>) -> Result<()> {
+        instructions::release_slug::handler(ctx)
+    }
+
+    /// Attaches an attestation to a journal entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    /// * `role` - An application-defined code describing the attester's role (e.g. therapist, coach).
+    /// * `statement_hash` - An optional hash of an off-chain statement made by the attester.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// This function lets any signer countersign a published entry. Each attester can hold at most
+    /// one attestation per entry.
+    pub fn attest_entry(
+        ctx: Context<AttestEntry>,
+        role: u8,
+        statement_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::attest_entry::handler(ctx, role, statement_hash)
+    }
+
+    /// Revokes an attestation previously attached to a journal entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing the accounts involved in the transaction.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - Returns an empty result on success.
+    ///
+    /// This function closes the attestation account and refunds its rent to the attester.
+    pub fn revoke_attestation(_ctx: Context<RevokeAttestation>) -> Result<()> {
+        instructions::revoke_attestation::handler(_ctx)
+    }
 }
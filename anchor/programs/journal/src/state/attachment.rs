@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Ensures `cid` looks like a content identifier for IPFS or Arweave.
+///
+/// Accepts a base58 IPFS CIDv0 (`Qm...`, 46 characters), a base32 IPFS CIDv1 (`b...`,
+/// lowercase) or a 43-character base64url Arweave transaction ID. Only the shape is checked;
+/// whether the content exists is up to the client.
+pub fn validate_attachment(cid: &str) -> Result<()> {
+    let bytes = cid.as_bytes();
+    let valid = bytes.len() <= MAX_ATTACHMENT_LENGTH as usize
+        && ((bytes.len() == 46
+            && cid.starts_with("Qm")
+            && bytes.iter().all(|c| BASE58_ALPHABET.contains(c)))
+            || (bytes.len() > 1
+                && bytes[0] == b'b'
+                && bytes[1..]
+                    .iter()
+                    .all(|c| c.is_ascii_lowercase() || (b'2'..=b'7').contains(c)))
+            || (bytes.len() == 43
+                && bytes
+                    .iter()
+                    .all(|c| c.is_ascii_alphanumeric() || *c == b'-' || *c == b'_')));
+    require!(valid, JournalError::InvalidAttachment);
+    Ok(())
+}
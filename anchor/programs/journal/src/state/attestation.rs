@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+/// Represents a third party's attestation of a journal entry.
+#[account]
+#[derive(InitSpace)]
+pub struct Attestation {
+    /// The public key of the attester.
+    pub attester: Pubkey,
+    /// The journal entry being attested.
+    pub entry: Pubkey,
+    /// An application-defined code describing the attester's role.
+    pub role: u8,
+    /// An optional hash of an off-chain statement made by the attester.
+    pub statement_hash: Option<[u8; 32]>,
+    /// The Unix timestamp at which the attestation was made.
+    pub attested_at: i64,
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+
+/// The kind of mutation recorded in an audit log.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum AuditAction {
+    Enabled,
+    Updated,
+    GuardianChanged,
+    Deleted,
+    Restored,
+    Sealed,
+    Witnessed,
+    Transferred,
+    Revealed,
+    TransferOffered,
+    Locked,
+    Moved,
+    Published,
+    Purged,
+    MetadataUpdated,
+    WitnessChanged,
+    EditWindowChanged,
+    AccessGranted,
+    RevisionsEnabled,
+}
+
+/// A single mutation recorded in an audit log.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct AuditRecord {
+    /// The signer that performed the mutation.
+    pub actor: Pubkey,
+    /// The kind of mutation.
+    pub action: AuditAction,
+    /// The Unix timestamp of the mutation.
+    pub timestamp: i64,
+    /// The hash of the entry's title and message after the mutation.
+    pub content_hash: [u8; 32],
+}
+
+/// Represents the tamper-evident mutation history of a journal entry.
+/// Records are kept in a ring buffer, so only the most recent ones are retained,
+/// while `total_records` counts every mutation ever recorded.
+#[account]
+#[derive(InitSpace)]
+pub struct AuditLog {
+    /// The journal entry being audited.
+    pub entry: Pubkey,
+    /// The total number of records ever appended.
+    pub total_records: u64,
+    /// The index that the next record overwrites once the buffer is full.
+    pub next_index: u8,
+    /// The retained records. Maximum of 16 records.
+    #[max_len(AUDIT_LOG_CAPACITY)]
+    pub records: Vec<AuditRecord>,
+}
+
+impl AuditLog {
+    /// Appends a record, overwriting the oldest one once the buffer is full.
+    pub fn push(&mut self, record: AuditRecord) {
+        if self.records.len() < AUDIT_LOG_CAPACITY as usize {
+            self.records.push(record);
+        } else {
+            self.records[self.next_index as usize] = record;
+        }
+        self.next_index = ((self.next_index as usize + 1) % AUDIT_LOG_CAPACITY as usize) as u8;
+        self.total_records += 1;
+    }
+}
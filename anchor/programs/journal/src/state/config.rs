@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT};
+use anchor_lang::solana_program::sysvar::{self, instructions as instructions_sysvar};
+
+use crate::constants::*;
+use crate::errors::JournalError;
+
+/// Represents the program-wide configuration.
+/// A single instance exists, owned by the configuration authority.
+#[account]
+#[derive(InitSpace)]
+pub struct ProgramConfig {
+    /// The public key allowed to update the configuration.
+    pub authority: Pubkey,
+    /// Whether write instructions invoked via CPI are rejected unless the caller is allowlisted.
+    pub reject_cpi: bool,
+    /// Programs allowed to invoke write instructions directly via CPI. Maximum of 8 programs.
+    #[max_len(MAX_CPI_ALLOWLIST)]
+    pub cpi_allowlist: Vec<Pubkey>,
+    /// The account receiving the program's share of marketplace sales.
+    pub treasury: Pubkey,
+    /// The program's share of marketplace sales, in basis points.
+    pub treasury_fee_bps: u16,
+    /// The fee for claiming a slug, in lamports, paid to the treasury.
+    pub slug_claim_fee: u64,
+    /// The maximum length of an entry title, in bytes. At most `MAX_TITLE_LENGTH`.
+    pub max_title_len: u32,
+    /// The maximum length of an entry message, in bytes. At most `MAX_MESSAGE_LENGTH`.
+    pub max_message_len: u32,
+    /// How long a deleted entry stays restorable before it can be purged, in seconds.
+    pub trash_grace_period: i64,
+}
+
+impl ProgramConfig {
+    /// Returns whether the current instruction is allowed under the configured CPI policy.
+    ///
+    /// Top-level invocations are always allowed. When `reject_cpi` is set, a CPI is only allowed
+    /// if it is made directly by the program invoked by the enclosing top-level instruction and
+    /// that program is on the allowlist. Nested CPIs are rejected, since their immediate caller
+    /// cannot be identified.
+    pub fn allows_invocation(&self, instructions: &AccountInfo) -> Result<bool> {
+        let stack_height = get_stack_height();
+        if !self.reject_cpi || stack_height == TRANSACTION_LEVEL_STACK_HEIGHT {
+            return Ok(true);
+        }
+        if stack_height != TRANSACTION_LEVEL_STACK_HEIGHT + 1 {
+            return Ok(false);
+        }
+
+        let current_index = instructions_sysvar::load_current_index_checked(instructions)?;
+        let current =
+            instructions_sysvar::load_instruction_at_checked(current_index as usize, instructions)?;
+        Ok(self.cpi_allowlist.contains(&current.program_id))
+    }
+
+    /// Ensures `title` and `message` fit the configured limits, which are byte lengths.
+    pub fn validate_entry(&self, title: &str, message: &str) -> Result<()> {
+        require!(!title.trim().is_empty(), JournalError::TitleEmpty);
+        require!(
+            title.len() <= self.max_title_len as usize,
+            JournalError::TitleTooLong
+        );
+        require!(
+            message.len() <= self.max_message_len as usize,
+            JournalError::MessageTooLong
+        );
+        Ok(())
+    }
+
+    /// Ensures `title` fits the configured limit and `ciphertext` is no longer than the largest
+    /// message it could encrypt plus the authentication tag.
+    pub fn validate_encrypted_entry(&self, title: &str, ciphertext: &[u8]) -> Result<()> {
+        self.validate_entry(title, "")?;
+        require!(
+            ciphertext.len() <= (self.max_message_len + CIPHERTEXT_OVERHEAD) as usize,
+            JournalError::MessageTooLong
+        );
+        Ok(())
+    }
+
+    /// Splits `amount` into the treasury's fee and the remainder, rounding the fee down.
+    pub fn split_fee(&self, amount: u64) -> (u64, u64) {
+        let fee = (amount as u128 * self.treasury_fee_bps as u128 / MAX_FEE_BPS as u128) as u64;
+        (fee, amount - fee)
+    }
+}
+
+/// The accounts every mutating instruction includes to enforce the program's CPI policy.
+///
+/// Validating them fails with `CpiNotAllowed` when the configuration rejects the invocation, so
+/// no handler has to remember the check.
+#[derive(Accounts)]
+pub struct CpiGuard<'info> {
+    /// The program configuration, holding the CPI policy.
+    #[account(seeds = [CONFIG_SEED.as_ref()], bump)]
+    pub config: Account<'info, ProgramConfig>,
+    /// CHECK: The instructions sysvar, used to identify the calling program.
+    #[account(
+        address = sysvar::instructions::ID,
+        constraint = config.allows_invocation(&instructions)? @ JournalError::CpiNotAllowed,
+    )]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{infos, set_stack_height, TestAccount};
+
+    fn config(treasury_fee_bps: u16) -> ProgramConfig {
+        ProgramConfig {
+            authority: Pubkey::default(),
+            reject_cpi: false,
+            cpi_allowlist: Vec::new(),
+            treasury: Pubkey::default(),
+            treasury_fee_bps,
+            slug_claim_fee: 0,
+            max_title_len: MAX_TITLE_LENGTH,
+            max_message_len: MAX_MESSAGE_LENGTH,
+            trash_grace_period: 0,
+        }
+    }
+
+    #[test]
+    fn split_fee_rounds_the_fee_down() {
+        assert_eq!(config(250).split_fee(1_000), (25, 975));
+        assert_eq!(config(250).split_fee(39), (0, 39));
+        assert_eq!(config(250).split_fee(41), (1, 40));
+    }
+
+    #[test]
+    fn split_fee_handles_the_bounds() {
+        assert_eq!(config(0).split_fee(1_000), (0, 1_000));
+        assert_eq!(config(MAX_FEE_BPS).split_fee(1_000), (1_000, 0));
+        assert_eq!(config(250).split_fee(0), (0, 0));
+        let (fee, rest) = config(MAX_FEE_BPS - 1).split_fee(u64::MAX);
+        assert_eq!(fee + rest, u64::MAX);
+    }
+
+    #[test]
+    fn cpi_is_allowed_only_directly_from_an_allowlisted_program() {
+        let caller = Pubkey::new_unique();
+        let mut instructions = [TestAccount::instructions(&caller)];
+        let instructions = &infos(&mut instructions)[0];
+        let mut config = ProgramConfig {
+            reject_cpi: true,
+            ..config(0)
+        };
+
+        set_stack_height(2);
+        assert!(!config.allows_invocation(instructions).unwrap());
+        config.cpi_allowlist.push(caller);
+        assert!(config.allows_invocation(instructions).unwrap());
+        // A program called by the allowlisted one is not the allowlisted program.
+        set_stack_height(3);
+        assert!(!config.allows_invocation(instructions).unwrap());
+        set_stack_height(1);
+        config.cpi_allowlist.clear();
+        assert!(config.allows_invocation(instructions).unwrap());
+    }
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+
+/// How an entry's message should be rendered.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ContentType {
+    /// Unformatted text.
+    PlainText,
+    /// Markdown.
+    Markdown,
+    /// A JSON object or array.
+    Json,
+}
+
+impl ContentType {
+    /// Ensures `message` is valid for the content type.
+    ///
+    /// Messages are always UTF-8 and bounded by the configured maximum length. JSON messages must
+    /// also be a single object or array whose brackets and strings are well-formed and nest at
+    /// most `MAX_JSON_DEPTH` deep; scalar values are not checked, to keep validation cheap.
+    pub fn validate(&self, message: &str) -> Result<()> {
+        if *self == ContentType::Json {
+            require!(is_well_formed_json(message), JournalError::InvalidJson);
+        }
+        Ok(())
+    }
+}
+
+fn is_well_formed_json(message: &str) -> bool {
+    let bytes = message.trim().as_bytes();
+    if !matches!(bytes.first(), Some(b'{' | b'[')) {
+        return false;
+    }
+
+    let mut open = Vec::new();
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, &byte) in bytes.iter().enumerate() {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if byte == b'\\' {
+                escaped = true;
+            } else if byte == b'"' {
+                in_string = false;
+            }
+            continue;
+        }
+        match byte {
+            b'"' => in_string = true,
+            b'{' | b'[' => {
+                open.push(byte);
+                if open.len() > MAX_JSON_DEPTH as usize {
+                    return false;
+                }
+            }
+            b'}' if open.pop() != Some(b'{') => return false,
+            b']' if open.pop() != Some(b'[') => return false,
+            _ => {}
+        }
+        // Nothing may follow the top-level value.
+        if open.is_empty() && i + 1 != bytes.len() {
+            return false;
+        }
+    }
+    open.is_empty()
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::JournalError;
+
+/// The kinds of writes a delegate may make on the owner's behalf.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum DelegateScope {
+    /// The delegate may create new entries.
+    CreateOnly,
+    /// The delegate may update the messages of existing entries.
+    UpdateOnly,
+    /// The delegate may both create and update entries.
+    CreateAndUpdate,
+}
+
+/// Authorizes a key, such as a mobile session key, to write to the owner's journal.
+#[account]
+#[derive(InitSpace)]
+pub struct WriteDelegate {
+    /// The owner of the journal.
+    pub owner: Pubkey,
+    /// The key allowed to write on the owner's behalf.
+    pub delegate: Pubkey,
+    /// The kinds of writes the delegate may make.
+    pub scope: DelegateScope,
+    /// The Unix timestamp after which the delegation is no longer valid.
+    pub expires_at: i64,
+}
+
+impl WriteDelegate {
+    /// Ensures the delegation has not expired and allows creating entries.
+    pub fn check_create(&self, now: i64) -> Result<()> {
+        self.check(now, self.scope != DelegateScope::UpdateOnly)
+    }
+
+    /// Ensures the delegation has not expired and allows updating entries.
+    pub fn check_update(&self, now: i64) -> Result<()> {
+        self.check(now, self.scope != DelegateScope::CreateOnly)
+    }
+
+    fn check(&self, now: i64, in_scope: bool) -> Result<()> {
+        require!(now <= self.expires_at, JournalError::DelegateExpired);
+        require!(in_scope, JournalError::DelegateOutOfScope);
+        Ok(())
+    }
+}
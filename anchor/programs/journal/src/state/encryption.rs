@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+
+/// How an encrypted entry's ciphertext was produced, so its readers can decrypt it.
+///
+/// The program never sees plaintext or secret keys: clients encrypt with an X25519 key
+/// agreement and XSalsa20-Poly1305, as in NaCl's `box`, and store the result as raw bytes.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct EncryptionHeader {
+    /// The nonce used to encrypt the message. It must never be reused with the same key.
+    pub nonce: [u8; 24],
+    /// The X25519 public key of the sender's ephemeral or long-term key pair.
+    pub encryption_pubkey: [u8; 32],
+    /// The version of the owner's registered key the message was encrypted to, telling clients
+    /// which of the owner's past secret keys decrypts it.
+    pub key_version: u32,
+}
+
+/// The X25519 public key an owner's entries are encrypted to, published so that writers and
+/// readers agree on it.
+#[account]
+#[derive(InitSpace)]
+pub struct KeyRegistry {
+    /// The owner of the key.
+    pub owner: Pubkey,
+    /// The owner's current X25519 public key.
+    pub encryption_pubkey: [u8; 32],
+    /// The number of keys the owner has published. Starts at 1 and increases with every
+    /// rotation.
+    pub key_version: u32,
+    /// The Unix timestamp at which the current key was published.
+    pub rotated_at: i64,
+}
+
+/// Shares an encrypted entry with a reader by holding the entry's decryption key wrapped to
+/// the reader's public key.
+#[account]
+#[derive(InitSpace)]
+pub struct AccessGrant {
+    /// The encrypted entry being shared.
+    pub entry: Pubkey,
+    /// The entry's owner, who granted access and receives the rent when it is revoked.
+    pub owner: Pubkey,
+    /// The reader the key is wrapped for.
+    pub reader: Pubkey,
+    /// The entry's decryption key, encrypted to the reader's X25519 key.
+    #[max_len(MAX_WRAPPED_KEY_LENGTH)]
+    pub wrapped_key: Vec<u8>,
+    /// The entry version the key was wrapped for. Once the entry is rewritten, the grant no
+    /// longer decrypts it and must be renewed.
+    pub entry_version: u64,
+    /// The Unix timestamp at which access was granted.
+    pub granted_at: i64,
+}
+
+impl KeyRegistry {
+    /// Publishes `encryption_pubkey` as the owner's current key at `now`, as a new version.
+    pub fn rotate(&mut self, owner: Pubkey, encryption_pubkey: [u8; 32], now: i64) {
+        self.owner = owner;
+        self.encryption_pubkey = encryption_pubkey;
+        self.key_version += 1;
+        self.rotated_at = now;
+    }
+
+    /// Ensures `header` was encrypted under the current key.
+    pub fn check_current(&self, header: &EncryptionHeader) -> Result<()> {
+        require_eq!(
+            header.key_version,
+            self.key_version,
+            JournalError::StaleEncryptionKey
+        );
+        Ok(())
+    }
+}
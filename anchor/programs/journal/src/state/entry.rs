@@ -0,0 +1,558 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::Discriminator;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::state::{
+    AccessGrant, Attestation, AuditAction, AuditLog, AuditRecord, ContentType, EncryptionHeader,
+    EntryView, Journal, JournalEntryRevision, Mood, PromptRef, Revision, RevisionHistory,
+    WeatherData,
+};
+
+/// The publication status of a journal entry.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum EntryStatus {
+    /// The entry is still being composed and is kept out of public views and attestations.
+    Draft,
+    /// The entry is visible to readers.
+    Published,
+}
+
+/// The topic area of a journal entry.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum EntryCategory {
+    /// Private life and reflections. The default for entries created without a category.
+    Personal,
+    /// Work and career.
+    Work,
+    /// Trips and places.
+    Travel,
+    /// Physical and mental health.
+    Health,
+    /// Anything else.
+    Other,
+}
+
+/// Optional settings for a new journal entry, beyond its title and message.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct EntryOptions {
+    /// Whether to keep the entry as a draft until it is published with `publish_entry`.
+    pub draft: bool,
+    /// A future Unix timestamp before which the entry cannot be published. Scheduled entries
+    /// are always created as drafts.
+    pub publish_at: Option<i64>,
+    /// Up to `MAX_TAGS` topics of at most `MAX_TAG_LENGTH` bytes each.
+    pub tags: Vec<String>,
+    /// The topic area of the entry.
+    pub category: EntryCategory,
+    /// How the message should be rendered. JSON messages must be well-formed.
+    pub content_type: ContentType,
+    /// The ISO 639-1 code of the entry's language, such as `*b"en"`, or zeros if unspecified.
+    pub lang: [u8; 2],
+    /// A weather snapshot supplied by the client. Its temperature must lie between -100 and 70
+    /// degrees Celsius.
+    pub weather: Option<WeatherData>,
+    /// The index of the prompt the entry answers in the prompt pack passed with it. The owner
+    /// must have bought the pack, unless they created it.
+    pub prompt: Option<u8>,
+}
+
+/// Represents the state of a journal entry.
+/// The `#[account]` attribute macro defines a struct that will be stored on-chain.
+/// The `#[derive(InitSpace)]` attribute macro is used to initialize the account with a space of 8 bytes.
+#[account]
+#[derive(InitSpace)]
+pub struct JournalEntryState {
+    /// The public key of the owner of the journal entry.
+    pub owner: Pubkey,
+    /// The entry's category. It directly follows the owner so that it sits at the fixed offset
+    /// `ENTRY_CATEGORY_OFFSET`, letting indexers filter by category with a memcmp.
+    pub category: EntryCategory,
+    /// The wallet the owner has offered the entry to, until it accepts the transfer.
+    pub pending_owner: Option<Pubkey>,
+    /// The wallet that created the entry. Unlike the owner, it never changes, so together with
+    /// the index it derives the entry's address.
+    pub author: Pubkey,
+    /// The entry's position in the author's journal, used to derive its address. Taken from the
+    /// author's profile counter, it is a per-author sequence number: strictly increasing in
+    /// creation order and without gaps, so clients can order entries created in the same second
+    /// and detect missing ones.
+    pub index: u64,
+    /// The bump of the entry's address.
+    pub bump: u8,
+    /// The named journal the entry is filed in, or `None` for the owner's default journal.
+    pub journal: Option<Pubkey>,
+    /// The author's entry created before this one, or `None` if this is their first.
+    pub prev_entry: Option<Pubkey>,
+    /// The author's entry created after this one, or `None` if this is their latest.
+    pub next_entry: Option<Pubkey>,
+    /// The title of the journal entry. At most `ProgramConfig::max_title_len` bytes.
+    #[max_len(MAX_TITLE_LENGTH)]
+    pub title: String,
+    /// The message of the journal entry. At most `ProgramConfig::max_message_len` bytes.
+    #[max_len(MAX_MESSAGE_LENGTH)]
+    pub message: String,
+    /// The encrypted message of an encrypted entry, whose plaintext `message` is left empty.
+    #[max_len(MAX_CIPHERTEXT_LENGTH)]
+    pub message_ciphertext: Vec<u8>,
+    /// How `message_ciphertext` was encrypted, if the entry is encrypted.
+    pub encryption: Option<EncryptionHeader>,
+    /// How the owner felt when writing the entry, if recorded.
+    pub mood: Option<Mood>,
+    /// The latitude where the entry was written, in micro-degrees, if recorded.
+    pub lat: Option<i32>,
+    /// The longitude where the entry was written, in micro-degrees, if recorded.
+    pub lng: Option<i32>,
+    /// The weather when the entry was written, as reported by the client, if recorded.
+    pub weather: Option<WeatherData>,
+    /// How the entry's message should be rendered.
+    pub content_type: ContentType,
+    /// The ISO 639-1 code of the entry's language in lowercase ASCII, or zeros if unspecified.
+    pub lang: [u8; 2],
+    /// The topics the entry is grouped under. Space for the maximum is always reserved, so
+    /// changing tags never reallocates the account.
+    #[max_len(MAX_TAGS, MAX_TAG_LENGTH)]
+    pub tags: Vec<String>,
+    /// Content identifiers of photos, audio and other files stored on IPFS or Arweave. Space
+    /// for the maximum is always reserved, so attaching files never reallocates the account.
+    #[max_len(MAX_ATTACHMENTS, MAX_ATTACHMENT_LENGTH)]
+    pub attachments: Vec<String>,
+    /// The content identifier of the entry's hero image, if any. Validated like attachments.
+    #[max_len(MAX_ATTACHMENT_LENGTH)]
+    pub cover_cid: Option<String>,
+    /// The commitment to the content of a private entry that has not been revealed yet, as
+    /// computed by `JournalEntryState::commitment`.
+    pub commitment: Option<[u8; 32]>,
+    /// The number of distinct wallets that have viewed the journal entry.
+    pub view_count: u64,
+    /// The guardian whose co-signature is required to delete or transfer the entry, if it is
+    /// protected.
+    pub guardian: Option<Pubkey>,
+    /// The second party asked to co-sign the entry's content, if any.
+    pub witness: Option<Pubkey>,
+    /// The content hash the witness signed, if it has. Clients compare it with the current
+    /// `content_hash` to tell whether the entry changed since.
+    pub witnessed_hash: Option<[u8; 32]>,
+    /// The Unix timestamp at which the witness signed, or 0 if it has not.
+    pub witnessed_at: i64,
+    /// Whether mutations of the entry must be recorded in its audit log.
+    pub audited: bool,
+    /// The Unix timestamp at which the entry was created.
+    pub created_at: i64,
+    /// The Unix timestamp at which the entry's content was last written.
+    pub updated_at: i64,
+    /// The number of content writes the entry has seen, used to detect concurrent edits.
+    pub version: u64,
+    /// The number of times the entry's content was edited after creation.
+    pub edit_count: u32,
+    /// The Unix timestamp of the entry's last edit after creation, or 0 if it was never edited.
+    pub last_edited_at: i64,
+    /// The Unix timestamp at which the entry was moved to the trash, if it is trashed.
+    pub deleted_at: Option<i64>,
+    /// How long after creation the entry's content may be edited, in seconds, if limited.
+    pub edit_window: Option<i64>,
+    /// The Unix timestamp until which the entry can be neither edited nor deleted, if locked.
+    pub unlock_at: Option<i64>,
+    /// Whether the entry is permanently immutable. Sealed entries cannot be edited or deleted.
+    pub is_sealed: bool,
+    /// Whether the entry is a draft or has been published.
+    pub status: EntryStatus,
+    /// The Unix timestamp before which the entry cannot be published, if it was scheduled.
+    pub publish_at: Option<i64>,
+    /// Whether the entry is hidden from the owner's feed. Archived entries keep their content.
+    pub is_archived: bool,
+    /// Whether updates of the entry must store a revision in its revision history.
+    pub revisioned: bool,
+    /// The purchased prompt the entry answers, if any.
+    pub prompt: Option<PromptRef>,
+}
+
+impl JournalEntryState {
+    /// Returns the account size, including the discriminator, for an entry with the given
+    /// title and body lengths. The body is the message of a plaintext entry or the ciphertext of
+    /// an encrypted one; the other is always empty. All other fields are fixed-size.
+    pub fn space(title_len: usize, body_len: usize) -> usize {
+        8 + JournalEntryState::INIT_SPACE
+            - MAX_TITLE_LENGTH as usize
+            - MAX_MESSAGE_LENGTH as usize
+            - MAX_CIPHERTEXT_LENGTH as usize
+            + title_len
+            + body_len
+    }
+
+    /// Returns the length of the entry's body: its message, or its ciphertext if encrypted.
+    pub fn body_len(&self) -> usize {
+        self.message.len() + self.message_ciphertext.len()
+    }
+
+    /// Ensures the entry is stored in plaintext.
+    pub fn check_plaintext(&self) -> Result<()> {
+        require!(self.encryption.is_none(), JournalError::EntryEncrypted);
+        Ok(())
+    }
+
+    /// Drops the ciphertext of an encrypted entry, before a plaintext message replaces it.
+    pub fn clear_encryption(&mut self) {
+        self.message_ciphertext.clear();
+        self.encryption = None;
+    }
+
+    /// Ensures `tags` fits the entry's tag limits.
+    pub fn validate_tags(tags: &[String]) -> Result<()> {
+        require!(tags.len() <= MAX_TAGS as usize, JournalError::TooManyTags);
+        for tag in tags {
+            require!(
+                !tag.is_empty() && tag.len() <= MAX_TAG_LENGTH as usize,
+                JournalError::InvalidTag
+            );
+        }
+        Ok(())
+    }
+
+    /// Ensures `lang` is two lowercase ASCII letters, or zeros for an unspecified language.
+    pub fn validate_lang(lang: [u8; 2]) -> Result<()> {
+        require!(
+            lang == [0; 2] || lang.iter().all(u8::is_ascii_lowercase),
+            JournalError::InvalidLanguage
+        );
+        Ok(())
+    }
+
+    /// Records a write of the entry's content at `now`, bumping its version, setting the creation
+    /// time on first write and counting every later write as an edit.
+    pub fn touch(&mut self, now: i64) {
+        self.version += 1;
+        if self.created_at == 0 {
+            self.created_at = now;
+        } else {
+            self.edit_count = self.edit_count.saturating_add(1);
+            self.last_edited_at = now;
+        }
+        self.updated_at = now;
+    }
+
+    /// Ensures the entry is not in the trash.
+    pub fn check_not_trashed(&self) -> Result<()> {
+        require!(self.deleted_at.is_none(), JournalError::EntryTrashed);
+        Ok(())
+    }
+
+    /// Ensures the entry has not been sealed.
+    pub fn check_not_sealed(&self) -> Result<()> {
+        require!(!self.is_sealed, JournalError::SealedEntry);
+        Ok(())
+    }
+
+    /// Ensures the entry is not time-locked.
+    pub fn check_unlocked(&self) -> Result<()> {
+        if let Some(unlock_at) = self.unlock_at {
+            require!(
+                Clock::get()?.unix_timestamp >= unlock_at,
+                JournalError::EntryLocked
+            );
+        }
+        Ok(())
+    }
+
+    /// Ensures the entry's content may be edited: it is neither trashed, sealed, time-locked nor
+    /// an unrevealed private entry, and its edit window, if any, has not closed.
+    pub fn check_editable(&self) -> Result<()> {
+        self.check_not_trashed()?;
+        require!(self.commitment.is_none(), JournalError::EntryNotRevealed);
+        self.check_not_sealed()?;
+        self.check_unlocked()?;
+        if let Some(edit_window) = self.edit_window {
+            require!(
+                Clock::get()?.unix_timestamp < self.created_at.saturating_add(edit_window),
+                JournalError::EditWindowClosed
+            );
+        }
+        Ok(())
+    }
+
+    /// Ensures the entry has been published.
+    pub fn check_published(&self) -> Result<()> {
+        require!(
+            self.status == EntryStatus::Published,
+            JournalError::EntryNotPublished
+        );
+        Ok(())
+    }
+
+    /// Returns whether the entry may be published at `now`: it was not scheduled, or its
+    /// publish time has come.
+    pub fn is_publish_time(&self, now: i64) -> bool {
+        self.publish_at.is_none_or(|publish_at| now >= publish_at)
+    }
+
+    /// Ensures the entry is still at `expected_version`, i.e. it was not written since the caller
+    /// read it.
+    pub fn check_version(&self, expected_version: u64) -> Result<()> {
+        require_eq!(self.version, expected_version, JournalError::StaleVersion);
+        Ok(())
+    }
+
+    /// Ensures the entry's guardian, if any, has signed the transaction.
+    pub fn check_guardian(&self, guardian: Option<&Signer>) -> Result<()> {
+        if let Some(expected) = self.guardian {
+            match guardian {
+                Some(signer) if signer.key() == expected => {}
+                _ => return err!(JournalError::GuardianSignatureRequired),
+            }
+        }
+        Ok(())
+    }
+
+    /// Files the entry in `journal`, counting it there.
+    pub fn join_journal(&mut self, journal: &mut Account<Journal>) {
+        journal.entry_count += 1;
+        self.journal = Some(journal.key());
+    }
+
+    /// Takes the entry out of its journal, if it is filed in one, which must then be passed as
+    /// `journal`. Fails with `JournalRequired` or `JournalMismatch` otherwise.
+    pub fn leave_journal(&mut self, journal: Option<&mut Account<Journal>>) -> Result<()> {
+        let Some(parent) = self.journal else {
+            return Ok(());
+        };
+        let journal = journal.ok_or(JournalError::JournalRequired)?;
+        require_keys_eq!(journal.key(), parent, JournalError::JournalMismatch);
+        journal.entry_count -= 1;
+        self.journal = None;
+        Ok(())
+    }
+
+    /// Returns the commitment to a private entry's content: the SHA-256 hash of the title's
+    /// length as a little-endian `u32`, the title, the message and the salt.
+    pub fn commitment(title: &str, message: &str, salt: &[u8; 32]) -> [u8; 32] {
+        hashv(&[
+            &(title.len() as u32).to_le_bytes(),
+            title.as_bytes(),
+            message.as_bytes(),
+            salt,
+        ])
+        .to_bytes()
+    }
+
+    /// Returns the hash of the entry's title and body.
+    pub fn content_hash(&self) -> [u8; 32] {
+        hashv(&[
+            self.title.as_bytes(),
+            self.message.as_bytes(),
+            &self.message_ciphertext,
+        ])
+        .to_bytes()
+    }
+
+    /// Stores the diff from `new_message` back to the current message in the revision history.
+    ///
+    /// Must be called before the message is replaced. Entries without revisions may omit the
+    /// history; revisioned entries fail with `RevisionHistoryRequired`.
+    pub fn record_revision(
+        &self,
+        revision_history: Option<&mut Account<RevisionHistory>>,
+        new_message: &str,
+    ) -> Result<()> {
+        let Some(revision_history) = revision_history else {
+            require!(!self.revisioned, JournalError::RevisionHistoryRequired);
+            return Ok(());
+        };
+
+        revision_history.push(Revision::diff(
+            &self.message,
+            new_message,
+            Clock::get()?.unix_timestamp,
+        ));
+
+        Ok(())
+    }
+
+    /// Appends a record of `action` to the entry's audit log.
+    ///
+    /// Entries without auditing may omit the log; audited entries fail with `AuditLogRequired`.
+    pub fn audit(
+        &self,
+        audit_log: Option<&mut Account<AuditLog>>,
+        actor: Pubkey,
+        action: AuditAction,
+    ) -> Result<()> {
+        let Some(audit_log) = audit_log else {
+            require!(!self.audited, JournalError::AuditLogRequired);
+            return Ok(());
+        };
+
+        audit_log.push(AuditRecord {
+            actor,
+            action,
+            timestamp: Clock::get()?.unix_timestamp,
+            content_hash: self.content_hash(),
+        });
+
+        Ok(())
+    }
+
+    /// Appends a record of `action` to the audit log of the entry at `key`, taken from `accounts`
+    /// if the entry is audited, for instructions that receive their entries as remaining accounts.
+    ///
+    /// The log must be the next account; audited entries fail with `AuditLogRequired` otherwise.
+    pub fn audit_next<'info>(
+        &self,
+        key: Pubkey,
+        accounts: &mut std::slice::Iter<'info, AccountInfo<'info>>,
+        actor: Pubkey,
+        action: AuditAction,
+    ) -> Result<()> {
+        if !self.audited {
+            return Ok(());
+        }
+
+        let info = accounts.next().ok_or(JournalError::AuditLogRequired)?;
+        require!(info.is_writable, ErrorCode::ConstraintMut);
+        let mut audit_log = Account::<AuditLog>::try_from(info)?;
+        require_keys_eq!(audit_log.entry, key, JournalError::AuditLogRequired);
+        self.audit(Some(&mut audit_log), actor, action)?;
+        audit_log.exit(&crate::ID)
+    }
+
+    /// Closes the entry's audit log and revision history as the entry is purged, sending their
+    /// rent to `destination`. Each must be passed if the entry has one; fails with
+    /// `AuditLogRequired` or `RevisionHistoryRequired` otherwise.
+    pub fn close_logs<'info>(
+        &self,
+        audit_log: Option<&Account<'info, AuditLog>>,
+        revision_history: Option<&Account<'info, RevisionHistory>>,
+        destination: &AccountInfo<'info>,
+    ) -> Result<()> {
+        require!(
+            !self.audited || audit_log.is_some(),
+            JournalError::AuditLogRequired
+        );
+        require!(
+            !self.revisioned || revision_history.is_some(),
+            JournalError::RevisionHistoryRequired
+        );
+        if let Some(audit_log) = audit_log {
+            audit_log.close(destination.clone())?;
+        }
+        if let Some(revision_history) = revision_history {
+            revision_history.close(destination.clone())?;
+        }
+        Ok(())
+    }
+
+    /// Closes the view records, attestations, access grants and snapshots of the entry at `key`
+    /// passed in `accounts`, each followed by the wallet that paid for it, which receives its
+    /// rent. Fails with `CompanionAccountMismatch` if an account is none of these, belongs to
+    /// another entry, or is not followed by its payer.
+    ///
+    /// Closed view records are taken off the entry's view count, so that it counts those left.
+    pub fn close_companions<'info>(
+        &mut self,
+        key: Pubkey,
+        accounts: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
+        let mut accounts = accounts.iter();
+        while let Some(info) = accounts.next() {
+            let payer = accounts
+                .next()
+                .ok_or(JournalError::CompanionAccountMismatch)?;
+            require!(
+                info.is_writable && payer.is_writable,
+                ErrorCode::ConstraintMut
+            );
+            let discriminator: [u8; 8] = info
+                .try_borrow_data()?
+                .get(..8)
+                .and_then(|bytes| bytes.try_into().ok())
+                .ok_or(JournalError::CompanionAccountMismatch)?;
+            if discriminator == EntryView::DISCRIMINATOR {
+                close_companion::<EntryView>(info, payer, key, |view| (view.entry, view.viewer))?;
+                self.view_count = self.view_count.saturating_sub(1);
+            } else if discriminator == Attestation::DISCRIMINATOR {
+                close_companion::<Attestation>(info, payer, key, |attestation| {
+                    (attestation.entry, attestation.attester)
+                })?;
+            } else if discriminator == AccessGrant::DISCRIMINATOR {
+                close_companion::<AccessGrant>(info, payer, key, |grant| {
+                    (grant.entry, grant.owner)
+                })?;
+            } else if discriminator == JournalEntryRevision::DISCRIMINATOR {
+                close_companion::<JournalEntryRevision>(info, payer, key, |snapshot| {
+                    (snapshot.entry, snapshot.owner)
+                })?;
+            } else {
+                return err!(JournalError::CompanionAccountMismatch);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Closes `info` as a `T` into `payer`, ensuring the entry and payer that `fields` reads from it
+/// are `key` and `payer`.
+fn close_companion<'info, T>(
+    info: &'info AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    key: Pubkey,
+    fields: fn(&T) -> (Pubkey, Pubkey),
+) -> Result<()>
+where
+    T: AccountSerialize + AccountDeserialize + Owner + Clone,
+{
+    let account = Account::<T>::try_from(info)?;
+    let (entry, paid_by) = fields(&account);
+    require!(
+        entry == key && paid_by == payer.key(),
+        JournalError::CompanionAccountMismatch
+    );
+    account.close(payer.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn editable_entry_passes() {
+        set_now(100);
+        let (_, entry) = entry(Pubkey::new_unique(), 0);
+        assert!(entry.check_editable().is_ok());
+    }
+
+    #[test]
+    fn trashed_entry_is_not_editable() {
+        set_now(100);
+        let (_, mut entry) = entry(Pubkey::new_unique(), 0);
+        entry.deleted_at = Some(50);
+        assert_eq!(
+            entry.check_editable(),
+            Err(JournalError::EntryTrashed.into())
+        );
+    }
+
+    #[test]
+    fn sealed_entry_is_not_editable() {
+        set_now(100);
+        let (_, mut entry) = entry(Pubkey::new_unique(), 0);
+        entry.is_sealed = true;
+        assert_eq!(
+            entry.check_editable(),
+            Err(JournalError::SealedEntry.into())
+        );
+    }
+
+    #[test]
+    fn locked_entry_is_editable_once_unlocked() {
+        let (_, mut entry) = entry(Pubkey::new_unique(), 0);
+        entry.unlock_at = Some(100);
+        set_now(99);
+        assert_eq!(
+            entry.check_editable(),
+            Err(JournalError::EntryLocked.into())
+        );
+        set_now(100);
+        assert!(entry.check_editable().is_ok());
+    }
+}
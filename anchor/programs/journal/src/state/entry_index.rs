@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::state::JournalEntryState;
+
+/// A page of a user's entry index, listing the addresses of their published entries. Drafts are
+/// kept off the index until they are published.
+/// Page `n` holds the entries with indexes from `n * ENTRY_INDEX_PAGE_CAPACITY` up to the next
+/// page, so a wallet can enumerate a journal by reading pages from page 0 until one is missing.
+#[account]
+#[derive(InitSpace)]
+pub struct EntryIndexPage {
+    /// The public key of the user whose entries are listed.
+    pub owner: Pubkey,
+    /// The number of the page.
+    pub page: u64,
+    /// The bump of the page's address.
+    pub bump: u8,
+    /// The addresses of the page's published entries that still exist, in the order they were
+    /// published.
+    #[max_len(ENTRY_INDEX_PAGE_CAPACITY)]
+    pub entries: Vec<Pubkey>,
+}
+
+impl EntryIndexPage {
+    /// Returns the number of the page listing the entry at `index`.
+    pub fn page_of(index: u64) -> u64 {
+        index / ENTRY_INDEX_PAGE_CAPACITY as u64
+    }
+
+    /// Lists the published `entry`, which must belong on this page.
+    pub fn list(&mut self, entry: &Account<JournalEntryState>) {
+        self.entries.push(entry.key());
+    }
+
+    /// Lists `entry` as it is published, ensuring this is the page it belongs on.
+    pub fn list_published(&mut self, entry: &Account<JournalEntryState>) -> Result<()> {
+        require!(
+            self.owner == entry.author && self.page == Self::page_of(entry.index),
+            JournalError::EntryIndexPageRequired
+        );
+        self.list(entry);
+        Ok(())
+    }
+}
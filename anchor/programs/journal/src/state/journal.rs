@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+
+/// A named collection of an owner's entries, such as a work journal or a dream journal.
+/// Entries outside any journal make up the owner's default, unnamed one.
+#[account]
+#[derive(InitSpace)]
+pub struct Journal {
+    /// The public key of the owner of the journal.
+    pub owner: Pubkey,
+    /// The journal's name, unique per owner. Maximum length is 32 characters.
+    #[max_len(MAX_JOURNAL_NAME_LENGTH)]
+    pub name: String,
+    /// What the journal is for. Maximum length is 200 characters.
+    #[max_len(MAX_JOURNAL_DESCRIPTION_LENGTH)]
+    pub description: String,
+    /// The number of entries currently filed in the journal.
+    pub entry_count: u64,
+    /// The bump of the journal's address.
+    pub bump: u8,
+}
+
+impl Journal {
+    /// Ensures `name` and `description` fit the journal limits.
+    pub fn validate(name: &str, description: &str) -> Result<()> {
+        require!(
+            !name.is_empty() && name.len() <= MAX_JOURNAL_NAME_LENGTH as usize,
+            JournalError::InvalidJournalName
+        );
+        require!(
+            description.len() <= MAX_JOURNAL_DESCRIPTION_LENGTH as usize,
+            JournalError::JournalDescriptionTooLong
+        );
+        Ok(())
+    }
+}
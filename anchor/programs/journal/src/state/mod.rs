@@ -0,0 +1,51 @@
+pub mod attachment;
+pub mod attestation;
+pub mod audit_log;
+pub mod config;
+pub mod content_type;
+pub mod delegate;
+pub mod encryption;
+pub mod entry;
+pub mod entry_index;
+pub mod journal;
+pub mod mood;
+pub mod multisig;
+pub mod notarization;
+pub mod pending;
+pub mod permit;
+pub mod profile;
+pub mod prompt_pack;
+pub mod revision;
+pub mod shared;
+pub mod slug;
+pub mod snapshot;
+pub mod stats;
+pub mod toc;
+pub mod view;
+pub mod weather;
+
+pub use attachment::*;
+pub use attestation::*;
+pub use audit_log::*;
+pub use config::*;
+pub use content_type::*;
+pub use delegate::*;
+pub use encryption::*;
+pub use entry::*;
+pub use entry_index::*;
+pub use journal::*;
+pub use mood::*;
+pub use multisig::*;
+pub use notarization::*;
+pub use pending::*;
+pub use permit::*;
+pub use profile::*;
+pub use prompt_pack::*;
+pub use revision::*;
+pub use shared::*;
+pub use slug::*;
+pub use snapshot::*;
+pub use stats::*;
+pub use toc::*;
+pub use view::*;
+pub use weather::*;
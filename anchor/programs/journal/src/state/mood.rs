@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+/// How the owner felt when writing an entry.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum Mood {
+    /// Very low.
+    VeryBad,
+    /// Low.
+    Bad,
+    /// Neither low nor high.
+    Neutral,
+    /// High.
+    Good,
+    /// Very high.
+    VeryGood,
+}
+
+/// Counts a user's entries by mood, for mood charts.
+#[account]
+#[derive(InitSpace)]
+pub struct MoodStats {
+    /// The public key of the user.
+    pub owner: Pubkey,
+    /// The number of entries currently recorded with each mood, indexed by `Mood` variant.
+    pub counts: [u64; 5],
+}
+
+impl MoodStats {
+    /// Moves an entry's contribution from its previous mood, if any, to its new one, if any.
+    pub fn record(&mut self, previous: Option<Mood>, current: Option<Mood>) {
+        if let Some(mood) = previous {
+            self.counts[mood as usize] = self.counts[mood as usize].saturating_sub(1);
+        }
+        if let Some(mood) = current {
+            self.counts[mood as usize] += 1;
+        }
+    }
+}
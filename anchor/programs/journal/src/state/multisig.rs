@@ -0,0 +1,214 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+
+/// A journal owned jointly by several wallets. Its entries are owned by this account, and
+/// every change to them must be approved by `threshold` of the owners.
+#[account]
+#[derive(InitSpace)]
+pub struct MultisigJournal {
+    /// The wallet that created the journal, used with `id` to derive its address.
+    pub creator: Pubkey,
+    /// The creator's identifier for the journal, letting one wallet create several.
+    pub id: u64,
+    /// The owners who may propose and approve changes.
+    #[max_len(MAX_MULTISIG_OWNERS)]
+    pub owners: Vec<Pubkey>,
+    /// The number of approvals a proposal needs before it can be executed.
+    pub threshold: u8,
+    /// The number of entries created in the journal, used to derive the next entry's address.
+    pub entry_count: u64,
+    /// The number of proposals made, used to derive the next proposal's address.
+    pub proposal_count: u64,
+    /// The bump of the journal's address.
+    pub bump: u8,
+}
+
+impl MultisigJournal {
+    /// Ensures `owners` are distinct and within the owner limit, and `threshold` is reachable.
+    pub fn validate(owners: &[Pubkey], threshold: u8) -> Result<()> {
+        require!(
+            (1..=MAX_MULTISIG_OWNERS as usize).contains(&owners.len())
+                && owners
+                    .iter()
+                    .enumerate()
+                    .all(|(i, owner)| !owners[..i].contains(owner)),
+            JournalError::InvalidMultisigOwners
+        );
+        require!(
+            (1..=owners.len()).contains(&(threshold as usize)),
+            JournalError::InvalidThreshold
+        );
+        Ok(())
+    }
+
+    /// Returns the bit representing `owner` in a proposal's approvals.
+    pub fn approval_bit(&self, owner: &Pubkey) -> Result<u8> {
+        let position = self
+            .owners
+            .iter()
+            .position(|o| o == owner)
+            .ok_or(JournalError::NotMultisigOwner)?;
+        Ok(1 << position)
+    }
+
+    /// Takes the index of the next entry, advancing the counter.
+    pub fn next_entry_index(&mut self) -> u64 {
+        let index = self.entry_count;
+        self.entry_count += 1;
+        index
+    }
+}
+
+/// The change a multisig proposal makes once approved.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum MultisigAction {
+    /// Create a new entry with the proposed title and message.
+    Create,
+    /// Replace the title and message of the proposal's entry.
+    Update,
+    /// Move the proposal's entry to the trash, or purge it once it has spent the grace period
+    /// there.
+    Delete,
+}
+
+/// A change to a multisig journal awaiting its owners' approvals.
+/// The proposer pays its rent and is refunded when it is executed or cancelled.
+#[account]
+#[derive(InitSpace)]
+pub struct MultisigProposal {
+    /// The multisig journal the proposal belongs to.
+    pub multisig: Pubkey,
+    /// The owner who made the proposal.
+    pub proposer: Pubkey,
+    /// The change to make.
+    pub action: MultisigAction,
+    /// The entry to update or delete, or `None` for a new entry.
+    pub entry: Option<Pubkey>,
+    /// The version of the entry the proposal was made against, or zero for a new entry. The
+    /// proposal fails with `StaleVersion` if the entry changes before it is executed.
+    pub expected_version: u64,
+    /// The proposed title, empty for deletions. At most `ProgramConfig::max_title_len` bytes.
+    #[max_len(MAX_TITLE_LENGTH)]
+    pub title: String,
+    /// The proposed message, empty for deletions. At most `ProgramConfig::max_message_len` bytes.
+    #[max_len(MAX_MESSAGE_LENGTH)]
+    pub message: String,
+    /// A bit per owner, in the order of the journal's owners, set once that owner approves.
+    pub approvals: u8,
+    /// The Unix timestamp at which the proposal was made.
+    pub proposed_at: i64,
+    /// The Unix timestamp from which the proposal can no longer be approved or executed.
+    pub expires_at: i64,
+}
+
+impl MultisigProposal {
+    /// Ensures the proposal has not expired.
+    pub fn check_not_expired(&self) -> Result<()> {
+        require!(
+            Clock::get()?.unix_timestamp < self.expires_at,
+            JournalError::ProposalExpired
+        );
+        Ok(())
+    }
+
+    /// Ensures the proposal makes `action` on `entry`, has not expired and has gathered the
+    /// journal's threshold of approvals.
+    pub fn check_executable(
+        &self,
+        multisig: &MultisigJournal,
+        action: MultisigAction,
+        entry: Option<Pubkey>,
+    ) -> Result<()> {
+        require!(
+            self.action == action && self.entry == entry,
+            JournalError::ProposalMismatch
+        );
+        self.check_not_expired()?;
+        require!(
+            self.approvals.count_ones() >= multisig.threshold as u32,
+            JournalError::ThresholdNotMet
+        );
+        Ok(())
+    }
+
+    /// Ensures `owner` may cancel the proposal: its proposer at any time, or any owner of the
+    /// journal once it has expired.
+    pub fn check_cancellable(&self, multisig: &MultisigJournal, owner: &Pubkey) -> Result<()> {
+        multisig.approval_bit(owner)?;
+        if *owner != self.proposer {
+            require!(
+                Clock::get()?.unix_timestamp >= self.expires_at,
+                JournalError::ProposalNotExpired
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::set_now;
+
+    fn multisig(owners: Vec<Pubkey>) -> MultisigJournal {
+        MultisigJournal {
+            creator: owners[0],
+            id: 0,
+            owners,
+            threshold: 1,
+            entry_count: 0,
+            proposal_count: 1,
+            bump: 0,
+        }
+    }
+
+    fn proposal(proposer: Pubkey) -> MultisigProposal {
+        MultisigProposal {
+            multisig: Pubkey::default(),
+            proposer,
+            action: MultisigAction::Create,
+            entry: None,
+            expected_version: 0,
+            title: String::new(),
+            message: String::new(),
+            approvals: 1,
+            proposed_at: 0,
+            expires_at: MULTISIG_PROPOSAL_LIFETIME,
+        }
+    }
+
+    #[test]
+    fn proposals_expire() {
+        let owner = Pubkey::new_unique();
+        let (multisig, proposal) = (multisig(vec![owner]), proposal(owner));
+        set_now(MULTISIG_PROPOSAL_LIFETIME - 1);
+        assert!(proposal
+            .check_executable(&multisig, MultisigAction::Create, None)
+            .is_ok());
+        set_now(MULTISIG_PROPOSAL_LIFETIME);
+        assert_eq!(
+            proposal.check_executable(&multisig, MultisigAction::Create, None),
+            Err(JournalError::ProposalExpired.into())
+        );
+    }
+
+    #[test]
+    fn only_the_proposer_can_cancel_before_expiry() {
+        let (proposer, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (multisig, proposal) = (multisig(vec![proposer, owner]), proposal(proposer));
+        set_now(0);
+        assert!(proposal.check_cancellable(&multisig, &proposer).is_ok());
+        assert_eq!(
+            proposal.check_cancellable(&multisig, &owner),
+            Err(JournalError::ProposalNotExpired.into())
+        );
+        set_now(MULTISIG_PROPOSAL_LIFETIME);
+        assert!(proposal.check_cancellable(&multisig, &owner).is_ok());
+        assert_eq!(
+            proposal.check_cancellable(&multisig, &Pubkey::new_unique()),
+            Err(JournalError::NotMultisigOwner.into())
+        );
+    }
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+
+/// The hash algorithm used to fingerprint a notarized document.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum HashAlgorithm {
+    Sha256,
+    Keccak256,
+    Blake3,
+}
+
+/// Represents a proof-of-existence record for an external document.
+/// Only the document's hash is stored, which keeps the account small and the content private.
+#[account]
+#[derive(InitSpace)]
+pub struct DocumentNotarization {
+    /// The public key of the wallet that notarized the document.
+    pub owner: Pubkey,
+    /// The hash of the document.
+    pub hash: [u8; 32],
+    /// The algorithm used to compute the hash.
+    pub algorithm: HashAlgorithm,
+    /// A short label describing the document. Maximum length is 32 characters.
+    #[max_len(MAX_LABEL_LENGTH)]
+    pub label: String,
+    /// The Unix timestamp at which the document was notarized.
+    pub notarized_at: i64,
+}
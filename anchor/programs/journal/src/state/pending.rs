@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+use crate::constants::*;
+
+/// A journal entry proposed by a guest, awaiting the journal owner's decision.
+/// The proposer pays its rent and is refunded when the owner approves or rejects it.
+#[account]
+#[derive(InitSpace)]
+pub struct PendingEntry {
+    /// The public key of the wallet that proposed the entry.
+    pub proposer: Pubkey,
+    /// The owner of the journal the entry is proposed for.
+    pub owner: Pubkey,
+    /// The proposed title. At most `ProgramConfig::max_title_len` bytes.
+    #[max_len(MAX_TITLE_LENGTH)]
+    pub title: String,
+    /// The proposed message. At most `ProgramConfig::max_message_len` bytes.
+    #[max_len(MAX_MESSAGE_LENGTH)]
+    pub message: String,
+    /// The Unix timestamp at which the entry was proposed.
+    pub proposed_at: i64,
+}
+
+impl PendingEntry {
+    /// Returns the PDA seed for `title`: its SHA-256 hash, since seeds are limited to 32 bytes
+    /// and titles may be longer.
+    pub fn title_seed(title: &str) -> [u8; 32] {
+        hash(title.as_bytes()).to_bytes()
+    }
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+/// The message an owner signs off-chain to authorize `create_entry_with_permit`.
+/// It is Borsh-serialized before signing, and includes the program ID so a permit
+/// cannot be replayed against another program.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct EntryPermit {
+    /// The ID of this program.
+    pub program_id: Pubkey,
+    /// The owner of the entry to be created.
+    pub owner: Pubkey,
+    /// The title of the entry.
+    pub title: String,
+    /// The message of the entry.
+    pub message: String,
+    /// A single-use value chosen by the owner.
+    pub nonce: u64,
+    /// The Unix timestamp after which the permit is no longer valid.
+    pub expiry: i64,
+}
+
+/// Marks a permit nonce as used so the permit cannot be replayed.
+#[account]
+#[derive(InitSpace)]
+pub struct PermitReceipt {
+    /// The owner who signed the permit.
+    pub owner: Pubkey,
+    /// The nonce of the permit.
+    pub nonce: u64,
+    /// The Unix timestamp at which the permit was used.
+    pub used_at: i64,
+}
@@ -0,0 +1,227 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::JournalError;
+use crate::state::{EntryIndexPage, EntryStatus, JournalEntryState};
+
+/// Represents a user's journal as a whole.
+/// Created with the user's first entry, it hands out the index each new entry is derived from
+/// and keeps statistics of the user's writing, so clients need not scan their entries.
+#[account]
+#[derive(InitSpace)]
+pub struct UserProfile {
+    /// The public key of the user.
+    pub owner: Pubkey,
+    /// The number of entries ever created, which is also the index of the next entry.
+    pub entry_count: u64,
+    /// The bump of the profile's address.
+    pub bump: u8,
+    /// The Unix timestamp of the owner's last heartbeat or write to their own entries.
+    pub last_heartbeat: i64,
+    /// How long without a heartbeat, in seconds, before anyone may publish the owner's drafts.
+    pub release_after: Option<i64>,
+    /// The wallet that may claim the owner's entries once the release delay has passed.
+    pub beneficiary: Option<Pubkey>,
+    /// The number of entries the owner wrote that are not in the trash.
+    pub active_entry_count: u64,
+    /// The bytes of titles and messages the owner wrote, summed over entry creation and every
+    /// content update.
+    pub total_bytes_written: u64,
+    /// The Unix timestamp at which the owner's first entry was created, or 0 if none was.
+    pub first_entry_at: i64,
+    /// The Unix timestamp at which the owner's latest entry was created, or 0 if none was.
+    pub last_entry_at: i64,
+    /// The oldest of the owner's entries, where walking their entry list starts.
+    pub head_entry: Option<Pubkey>,
+    /// The newest of the owner's entries, which new entries are linked after.
+    pub tail_entry: Option<Pubkey>,
+}
+
+impl UserProfile {
+    /// Claims the next entry index, recording the profile's owner and bump on first use.
+    pub fn next_entry_index(&mut self, owner: Pubkey, bump: u8) -> u64 {
+        self.owner = owner;
+        self.bump = bump;
+        let index = self.entry_count;
+        self.entry_count += 1;
+        index
+    }
+
+    /// Counts the newly created `entry` in the owner's statistics, returning whether it is the
+    /// owner's first entry.
+    pub fn record_entry_created(&mut self, entry: &JournalEntryState) -> bool {
+        self.active_entry_count += 1;
+        self.record_bytes_written(entry.title.len() + entry.body_len());
+        let first_entry = self.first_entry_at == 0;
+        if first_entry {
+            self.first_entry_at = entry.created_at;
+        }
+        self.last_entry_at = entry.created_at;
+        first_entry
+    }
+
+    /// Adds `bytes` of written content to the owner's statistics.
+    pub fn record_bytes_written(&mut self, bytes: usize) {
+        self.total_bytes_written = self.total_bytes_written.saturating_add(bytes as u64);
+    }
+
+    /// Stops counting an entry moved to the trash, or closed without passing through it, as
+    /// active.
+    pub fn record_entry_trashed(&mut self) {
+        self.active_entry_count = self.active_entry_count.saturating_sub(1);
+    }
+
+    /// Counts an entry restored from the trash as active again.
+    pub fn record_entry_restored(&mut self) {
+        self.active_entry_count += 1;
+    }
+
+    /// Links the newly created `entry` after the owner's latest entry, which must then be passed
+    /// as `tail`. Fails with `EntryListMismatch` otherwise.
+    pub fn append_entry(
+        &mut self,
+        entry: &mut Account<JournalEntryState>,
+        tail: Option<&mut Account<JournalEntryState>>,
+    ) -> Result<()> {
+        if let Some(tail) = Self::neighbor(self.tail_entry, tail)? {
+            tail.next_entry = Some(entry.key());
+        } else {
+            self.head_entry = Some(entry.key());
+        }
+        entry.prev_entry = self.tail_entry;
+        self.tail_entry = Some(entry.key());
+        Ok(())
+    }
+
+    /// Unlinks `entry` from the owner's entry list before it is closed. Its neighbors in the
+    /// list, if it has any, must be passed as `prev` and `next`; fails with `EntryListMismatch`
+    /// otherwise.
+    pub fn unlink_entry(
+        &mut self,
+        entry: &mut Account<JournalEntryState>,
+        prev: Option<&mut Account<JournalEntryState>>,
+        next: Option<&mut Account<JournalEntryState>>,
+    ) -> Result<()> {
+        if let Some(prev) = Self::neighbor(entry.prev_entry, prev)? {
+            prev.next_entry = entry.next_entry;
+        } else if self.head_entry == Some(entry.key()) {
+            self.head_entry = entry.next_entry;
+        }
+        if let Some(next) = Self::neighbor(entry.next_entry, next)? {
+            next.prev_entry = entry.prev_entry;
+        } else if self.tail_entry == Some(entry.key()) {
+            self.tail_entry = entry.prev_entry;
+        }
+        entry.prev_entry = None;
+        entry.next_entry = None;
+        Ok(())
+    }
+
+    /// Records the newly created `entry` on its entry index `page`, recording the page's owner,
+    /// number and bump on first use. Published entries are listed on the page at once; drafts are
+    /// listed when they are published.
+    pub fn index_entry(
+        &mut self,
+        page: &mut Account<EntryIndexPage>,
+        entry: &Account<JournalEntryState>,
+        bump: u8,
+    ) {
+        page.owner = self.owner;
+        page.page = EntryIndexPage::page_of(entry.index);
+        page.bump = bump;
+        if entry.status == EntryStatus::Published {
+            page.list(entry);
+        }
+    }
+
+    /// Removes `entry` from its entry index page before it is closed. The page must be passed if
+    /// the entry is published; fails with `EntryIndexPageRequired` otherwise.
+    pub fn unindex_entry(
+        &self,
+        entry: &Account<JournalEntryState>,
+        page: Option<&mut Account<EntryIndexPage>>,
+    ) -> Result<()> {
+        if entry.status != EntryStatus::Published {
+            return Ok(());
+        }
+        let page = page.ok_or(JournalError::EntryIndexPageRequired)?;
+        page.entries.retain(|key| *key != entry.key());
+        Ok(())
+    }
+
+    /// Returns the passed neighboring entry if one is `expected`, ensuring it is that entry.
+    fn neighbor<'a, 'info>(
+        expected: Option<Pubkey>,
+        account: Option<&'a mut Account<'info, JournalEntryState>>,
+    ) -> Result<Option<&'a mut Account<'info, JournalEntryState>>> {
+        let Some(expected) = expected else {
+            return Ok(None);
+        };
+        let account = account.ok_or(JournalError::EntryListMismatch)?;
+        require_keys_eq!(account.key(), expected, JournalError::EntryListMismatch);
+        Ok(Some(account))
+    }
+
+    /// Ensures the release delay has passed since the owner's last heartbeat.
+    pub fn check_inactive(&self, now: i64) -> Result<()> {
+        let release_after = self
+            .release_after
+            .ok_or(JournalError::ReleaseNotConfigured)?;
+        require!(
+            now >= self.last_heartbeat.saturating_add(release_after),
+            JournalError::OwnerStillActive
+        );
+        Ok(())
+    }
+
+    /// Counts a write signed by `signer` at `now` as a sign of life if the profile is theirs.
+    /// Writes to entries another wallet authored reach that wallet's profile, so they do not count.
+    pub fn record_activity(&mut self, signer: Pubkey, now: i64) {
+        if self.owner == signer {
+            self.last_heartbeat = now;
+        }
+    }
+
+    /// Records a sign of life from the owner at `now`, recording the profile's owner and bump on
+    /// first use.
+    pub fn record_heartbeat(&mut self, owner: Pubkey, bump: u8, now: i64) {
+        self.owner = owner;
+        self.bump = bump;
+        self.last_heartbeat = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::EntryIndexPage;
+    use crate::test_utils::*;
+
+    /// Indexes a new entry with `status` on a fresh page, returning the listed entries.
+    fn index(status: EntryStatus) -> Vec<Pubkey> {
+        let owner = Pubkey::new_unique();
+        let (_, mut profile) = profile(owner);
+        let (page_key, page) = index_page(owner, 0);
+        let bump = page.bump;
+        let (entry_key, mut entry) = entry(owner, 0);
+        entry.status = status;
+        let mut accounts = [
+            TestAccount::program(page_key, &page),
+            TestAccount::program(entry_key, &entry),
+        ];
+        let infos = infos(&mut accounts);
+        let mut page = Account::<EntryIndexPage>::try_from(&infos[0]).unwrap();
+        let entry = Account::<JournalEntryState>::try_from(&infos[1]).unwrap();
+        profile.index_entry(&mut page, &entry, bump);
+        page.entries.clone()
+    }
+
+    #[test]
+    fn published_entries_are_listed() {
+        assert_eq!(index(EntryStatus::Published).len(), 1);
+    }
+
+    #[test]
+    fn drafts_are_not_listed() {
+        assert!(index(EntryStatus::Draft).is_empty());
+    }
+}
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+
+/// A set of journaling prompts published by a creator for sale. The prompts themselves are
+/// public; buying the pack grants the right to create entries answering them.
+#[account]
+#[derive(InitSpace)]
+pub struct PromptPack {
+    /// The public key of the creator, who receives the proceeds of sales.
+    pub creator: Pubkey,
+    /// The name of the pack, unique per creator. Maximum length is 32 characters.
+    #[max_len(MAX_PACK_NAME_LENGTH)]
+    pub name: String,
+    /// The price of the pack, in lamports.
+    pub price: u64,
+    /// The prompts in the pack. Maximum of 10 prompts of 200 characters each.
+    #[max_len(MAX_PROMPTS_PER_PACK, MAX_PROMPT_LENGTH)]
+    pub prompts: Vec<String>,
+}
+
+/// Grants a wallet access to a purchased prompt pack.
+/// One account exists per (pack, buyer) pair.
+#[account]
+#[derive(InitSpace)]
+pub struct PromptPackAccess {
+    /// The wallet that bought the pack.
+    pub buyer: Pubkey,
+    /// The prompt pack that was purchased.
+    pub pack: Pubkey,
+    /// The price paid, in lamports.
+    pub price_paid: u64,
+    /// The Unix timestamp of the purchase.
+    pub purchased_at: i64,
+}
+
+/// A reference from an entry to the prompt it answers.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct PromptRef {
+    /// The prompt pack holding the prompt.
+    pub pack: Pubkey,
+    /// The position of the prompt in the pack.
+    pub index: u8,
+}
+
+impl PromptPack {
+    /// Returns a reference to the prompt at `index`, for an entry created by `owner`. The owner
+    /// must be the pack's creator or hold `access` to the pack at `key`.
+    pub fn reference(
+        &self,
+        key: Pubkey,
+        access: Option<&PromptPackAccess>,
+        owner: Pubkey,
+        index: u8,
+    ) -> Result<PromptRef> {
+        require!(
+            (index as usize) < self.prompts.len(),
+            JournalError::InvalidPromptIndex
+        );
+        let purchased = access.is_some_and(|access| access.pack == key && access.buyer == owner);
+        require!(
+            owner == self.creator || purchased,
+            JournalError::PromptPackNotPurchased
+        );
+        Ok(PromptRef { pack: key, index })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pack(creator: Pubkey) -> PromptPack {
+        PromptPack {
+            creator,
+            name: "Gratitude".to_string(),
+            price: 1_000,
+            prompts: vec!["What went well today?".to_string()],
+        }
+    }
+
+    fn access(pack: Pubkey, buyer: Pubkey) -> PromptPackAccess {
+        PromptPackAccess {
+            buyer,
+            pack,
+            price_paid: 1_000,
+            purchased_at: 0,
+        }
+    }
+
+    #[test]
+    fn buyers_and_the_creator_can_reference_prompts() {
+        let (key, creator, buyer) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let pack = pack(creator);
+
+        let prompt = pack
+            .reference(key, Some(&access(key, buyer)), buyer, 0)
+            .unwrap();
+        assert_eq!((prompt.pack, prompt.index), (key, 0));
+        assert!(pack.reference(key, None, creator, 0).is_ok());
+    }
+
+    #[test]
+    fn referencing_a_prompt_requires_a_purchase_of_the_pack() {
+        let (key, creator, buyer) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let pack = pack(creator);
+        let not_purchased = Some(JournalError::PromptPackNotPurchased.into());
+
+        assert_eq!(pack.reference(key, None, buyer, 0).err(), not_purchased);
+        // Access to another pack, or bought by another wallet, does not count.
+        let other_pack = access(Pubkey::new_unique(), buyer);
+        assert_eq!(
+            pack.reference(key, Some(&other_pack), buyer, 0).err(),
+            not_purchased
+        );
+        let other_buyer = access(key, Pubkey::new_unique());
+        assert_eq!(
+            pack.reference(key, Some(&other_buyer), buyer, 0).err(),
+            not_purchased
+        );
+    }
+
+    #[test]
+    fn referencing_a_missing_prompt_fails() {
+        let (key, creator) = (Pubkey::new_unique(), Pubkey::new_unique());
+        assert_eq!(
+            pack(creator).reference(key, None, creator, 1).err(),
+            Some(JournalError::InvalidPromptIndex.into())
+        );
+    }
+}
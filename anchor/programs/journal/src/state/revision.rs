@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+use crate::constants::*;
+
+/// A compact diff that restores an entry's message to its previous value.
+///
+/// The diff keeps the bytes the update replaced: applying it to the updated message keeps its
+/// first `prefix_len` and last `suffix_len` bytes and puts `removed` back in between.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct Revision {
+    /// The hash of the entry's message before the update.
+    pub prior_hash: [u8; 32],
+    /// The number of leading bytes shared by the old and new message.
+    pub prefix_len: u16,
+    /// The number of trailing bytes shared by the old and new message.
+    pub suffix_len: u16,
+    /// The bytes of the old message that the update replaced.
+    #[max_len(MAX_MESSAGE_LENGTH)]
+    pub removed: Vec<u8>,
+    /// The Unix timestamp of the update.
+    pub revised_at: i64,
+}
+
+impl Revision {
+    /// Builds the diff that turns `new` back into `old`.
+    pub fn diff(old: &str, new: &str, revised_at: i64) -> Self {
+        let prior_hash = hash(old.as_bytes()).to_bytes();
+        let (old, new) = (old.as_bytes(), new.as_bytes());
+        let prefix_len = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+        let suffix_len = old[prefix_len..]
+            .iter()
+            .rev()
+            .zip(new[prefix_len..].iter().rev())
+            .take_while(|(a, b)| a == b)
+            .count();
+        Self {
+            prior_hash,
+            prefix_len: prefix_len as u16,
+            suffix_len: suffix_len as u16,
+            removed: old[prefix_len..old.len() - suffix_len].to_vec(),
+            revised_at,
+        }
+    }
+
+    /// Returns the length of the message this diff restores.
+    pub fn restored_len(&self) -> usize {
+        self.prefix_len as usize + self.suffix_len as usize + self.removed.len()
+    }
+
+    /// Applies the diff to `current`, returning the bytes of the previous message, or `None` if
+    /// `current` is not the message the diff was computed from.
+    pub fn apply(&self, current: &str) -> Option<Vec<u8>> {
+        let current = current.as_bytes();
+        let suffix_start = current.len().checked_sub(self.suffix_len as usize)?;
+        let prefix = current.get(..self.prefix_len as usize)?;
+        let suffix = current.get(suffix_start.max(prefix.len())..)?;
+        let mut restored = Vec::with_capacity(self.restored_len());
+        restored.extend_from_slice(prefix);
+        restored.extend_from_slice(&self.removed);
+        restored.extend_from_slice(suffix);
+        (hash(&restored).to_bytes() == self.prior_hash).then_some(restored)
+    }
+}
+
+/// Represents the recent revisions of a journal entry's message.
+/// At most `depth` revisions are retained; the oldest is discarded when a new one is pushed.
+#[account]
+#[derive(InitSpace)]
+pub struct RevisionHistory {
+    /// The journal entry whose revisions are stored.
+    pub entry: Pubkey,
+    /// The number of revisions retained, chosen when the history is enabled.
+    pub depth: u8,
+    /// The total number of revisions ever recorded.
+    pub total_revisions: u64,
+    /// The retained revisions, oldest first. Maximum of 8 revisions.
+    #[max_len(MAX_REVISION_DEPTH)]
+    pub revisions: Vec<Revision>,
+}
+
+impl RevisionHistory {
+    /// Returns the account size, including the discriminator, for a history of the given depth.
+    pub fn space(depth: u8) -> usize {
+        8 + RevisionHistory::INIT_SPACE
+            - (MAX_REVISION_DEPTH as usize - depth as usize) * Revision::INIT_SPACE
+    }
+
+    /// Appends a revision, discarding the oldest one once `depth` revisions are retained.
+    pub fn push(&mut self, revision: Revision) {
+        if self.revisions.len() == self.depth as usize {
+            self.revisions.remove(0);
+        }
+        self.revisions.push(revision);
+        self.total_revisions += 1;
+    }
+
+    /// Returns the length of the message the most recent revision restores, or `current_len`
+    /// if there is none.
+    pub fn restored_len(&self, current_len: usize) -> usize {
+        self.revisions
+            .last()
+            .map_or(current_len, Revision::restored_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(old: &str, new: &str) {
+        let revision = Revision::diff(old, new, 0);
+        assert_eq!(revision.restored_len(), old.len());
+        assert_eq!(revision.apply(new).as_deref(), Some(old.as_bytes()));
+    }
+
+    #[test]
+    fn diff_restores_the_previous_message() {
+        round_trip("dear diary", "dear diary");
+        round_trip("dear diary", "dear diary, today");
+        round_trip("dear diary", "oh dear diary");
+        round_trip("dear diary", "dear journal");
+        round_trip("dear diary", "");
+        round_trip("", "dear diary");
+        round_trip("aaaa", "aa");
+        round_trip("aa", "aaaa");
+    }
+
+    #[test]
+    fn diff_keeps_only_the_replaced_bytes() {
+        let revision = Revision::diff("the quick fox", "the slow fox", 7);
+        assert_eq!(revision.prefix_len, 4);
+        assert_eq!(revision.suffix_len, 4);
+        assert_eq!(revision.removed, b"quick");
+        assert_eq!(revision.revised_at, 7);
+    }
+
+    #[test]
+    fn apply_rejects_a_different_message() {
+        let revision = Revision::diff("dear diary", "dear journal", 0);
+        assert_eq!(revision.apply("Dear journal"), None);
+        assert_eq!(revision.apply("dear"), None);
+        assert_eq!(revision.apply(""), None);
+    }
+
+    #[test]
+    fn history_discards_the_oldest_revision() {
+        let mut history = RevisionHistory {
+            entry: Pubkey::default(),
+            depth: 2,
+            total_revisions: 0,
+            revisions: Vec::new(),
+        };
+        assert_eq!(history.restored_len(5), 5);
+        for (revised_at, (old, new)) in [("a", "ab"), ("ab", "abc"), ("abc", "abcd")]
+            .into_iter()
+            .enumerate()
+        {
+            history.push(Revision::diff(old, new, revised_at as i64));
+        }
+        assert_eq!(history.total_revisions, 3);
+        let revised_at: Vec<i64> = history.revisions.iter().map(|r| r.revised_at).collect();
+        assert_eq!(revised_at, [1, 2]);
+        assert_eq!(history.restored_len(4), 3);
+    }
+}
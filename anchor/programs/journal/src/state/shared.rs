@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::JournalError;
+use crate::state::JournalEntryState;
+
+/// What a member of a shared journal may do, each role including the ones before it.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, InitSpace,
+)]
+pub enum MemberRole {
+    /// May read the journal, including encrypted entries shared with them.
+    Reader,
+    /// May add entries and edit or delete their own.
+    Writer,
+    /// May edit or delete any entry and manage membership.
+    Admin,
+}
+
+/// What happens to a removed member's entries.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum RetentionPolicy {
+    /// The entries stay in the journal, editable only by admins.
+    Retain,
+    /// The entries stay in the journal and are sealed, so nobody can change them.
+    Freeze,
+    /// The entries leave the journal and become the removing admin's own entries.
+    TransferToAdmin,
+}
+
+/// A journal that several wallets write to. Its entries are owned by this account, while
+/// their authors are the members who wrote them.
+#[account]
+#[derive(InitSpace)]
+pub struct SharedJournal {
+    /// The wallet that created the journal, used with `id` to derive its address.
+    pub creator: Pubkey,
+    /// The creator's identifier for the journal, letting one wallet create several.
+    pub id: u64,
+    /// The number of current members.
+    pub member_count: u32,
+    /// The bump of the journal's address.
+    pub bump: u8,
+}
+
+/// A wallet's membership of a shared journal.
+#[account]
+#[derive(InitSpace)]
+pub struct JournalMember {
+    /// The shared journal.
+    pub journal: Pubkey,
+    /// The member's wallet.
+    pub member: Pubkey,
+    /// What the member may do.
+    pub role: MemberRole,
+    /// The Unix timestamp at which the member joined.
+    pub joined_at: i64,
+    /// The number of entries the member wrote since joining that are still in the journal.
+    /// Removing the member seals or transfers all of them before the membership is closed.
+    pub entry_count: u64,
+}
+
+/// An admin's offer of membership, which takes effect only once the invitee accepts it.
+/// The inviter pays its rent and is refunded when it is accepted or cancelled.
+#[account]
+#[derive(InitSpace)]
+pub struct Invitation {
+    /// The shared journal.
+    pub journal: Pubkey,
+    /// The wallet invited to join.
+    pub invitee: Pubkey,
+    /// The role the invitee gets on accepting.
+    pub role: MemberRole,
+    /// The admin who sent the invitation.
+    pub inviter: Pubkey,
+    /// The Unix timestamp at which the invitation was sent.
+    pub invited_at: i64,
+}
+
+impl JournalMember {
+    /// Ensures the member has at least `role`.
+    pub fn check_role(&self, role: MemberRole) -> Result<()> {
+        require!(self.role >= role, JournalError::InsufficientRole);
+        Ok(())
+    }
+
+    /// Returns whether `entry` is counted in the member's entry count: written by the member
+    /// during this membership. Entries from an earlier membership were settled when it ended.
+    pub fn counts(&self, entry: &JournalEntryState) -> bool {
+        entry.author == self.member && entry.created_at >= self.joined_at
+    }
+
+    /// Stops counting `entry`, which is leaving the journal or being sealed, if it was counted.
+    pub fn record_entry_removed(&mut self, entry: &JournalEntryState) {
+        if self.counts(entry) {
+            self.entry_count = self.entry_count.saturating_sub(1);
+        }
+    }
+
+    /// Ensures the member may edit or delete `entry`: admins may change any entry of the
+    /// journal, writers only their own.
+    pub fn check_can_modify(&self, entry: &JournalEntryState) -> Result<()> {
+        require_keys_eq!(entry.owner, self.journal, JournalError::NotInSharedJournal);
+        if self.role != MemberRole::Admin {
+            self.check_role(MemberRole::Writer)?;
+            require_keys_eq!(entry.author, self.member, JournalError::InsufficientRole);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::JournalError;
+use crate::state::{Journal, TableOfContents, UserProfile};
+
+/// Maps a vanity slug to a journal account.
+/// The account address is derived from the slug, so the set of slug accounts forms a global
+/// registry in which each slug is unique.
+#[account]
+#[derive(InitSpace)]
+pub struct SlugRecord {
+    /// The public key of the wallet holding the slug.
+    pub owner: Pubkey,
+    /// The profile, journal or table of contents of the owner that the slug resolves to.
+    pub target: Pubkey,
+    /// The slug itself. Maximum length is 32 characters.
+    #[max_len(MAX_SLUG_LENGTH)]
+    pub slug: String,
+    /// The Unix timestamp at which the slug was claimed.
+    pub claimed_at: i64,
+}
+
+impl SlugRecord {
+    /// Ensures `slug` is URL-safe: lowercase ASCII letters, digits and hyphens, not starting or
+    /// ending with a hyphen, within the length bounds.
+    pub fn validate(slug: &str) -> Result<()> {
+        let bytes = slug.as_bytes();
+        require!(
+            (MIN_SLUG_LENGTH as usize..=MAX_SLUG_LENGTH as usize).contains(&bytes.len())
+                && bytes
+                    .iter()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || *c == b'-')
+                && bytes.first() != Some(&b'-')
+                && bytes.last() != Some(&b'-'),
+            JournalError::InvalidSlug
+        );
+        Ok(())
+    }
+
+    /// Ensures `target` is a profile, journal or table of contents held by `owner`, so a slug
+    /// can only point at its holder's own pages.
+    pub fn check_target(target: &AccountInfo, owner: Pubkey) -> Result<()> {
+        let data = target.try_borrow_data()?;
+        let target_owner = if let Ok(profile) = UserProfile::try_deserialize(&mut &data[..]) {
+            profile.owner
+        } else if let Ok(journal) = Journal::try_deserialize(&mut &data[..]) {
+            journal.owner
+        } else if let Ok(toc) = TableOfContents::try_deserialize(&mut &data[..]) {
+            toc.owner
+        } else {
+            return err!(JournalError::InvalidSlugTarget);
+        };
+        require_keys_eq!(target_owner, owner, JournalError::NotOwner);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{entry, infos, profile, TestAccount};
+
+    #[test]
+    fn slugs_point_at_their_holders_pages() {
+        let owner = Pubkey::new_unique();
+        let (key, profile) = profile(owner);
+        let mut accounts = [TestAccount::program(key, &profile)];
+        let accts = infos(&mut accounts);
+
+        assert!(SlugRecord::check_target(&accts[0], owner).is_ok());
+        assert_eq!(
+            SlugRecord::check_target(&accts[0], Pubkey::new_unique()),
+            Err(JournalError::NotOwner.into())
+        );
+    }
+
+    #[test]
+    fn slugs_cannot_point_at_other_accounts() {
+        let owner = Pubkey::new_unique();
+        let (key, entry) = entry(owner, 0);
+        let mut accounts = [TestAccount::program(key, &entry)];
+        let accts = infos(&mut accounts);
+
+        assert_eq!(
+            SlugRecord::check_target(&accts[0], owner),
+            Err(JournalError::InvalidSlugTarget.into())
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::state::JournalEntryState;
+
+/// A full copy of an entry's content as it was before an update.
+///
+/// Unlike the bounded revision history, snapshots are never overwritten: each one lives in its
+/// own account until the owner prunes it.
+#[account]
+#[derive(InitSpace)]
+pub struct JournalEntryRevision {
+    /// The entry the snapshot was taken of.
+    pub entry: Pubkey,
+    /// The owner of the entry, who paid for the snapshot and receives its rent when pruned.
+    pub owner: Pubkey,
+    /// The entry's edit count when the snapshot was taken, used to derive its address.
+    pub index: u32,
+    /// The entry's title before the update.
+    #[max_len(MAX_TITLE_LENGTH)]
+    pub title: String,
+    /// The entry's message before the update.
+    #[max_len(MAX_MESSAGE_LENGTH)]
+    pub message: String,
+    /// The Unix timestamp of the update.
+    pub snapshotted_at: i64,
+}
+
+impl JournalEntryRevision {
+    /// Returns the account size, including the discriminator, for a snapshot of an entry with
+    /// the given title and message lengths.
+    pub fn space(title_len: usize, message_len: usize) -> usize {
+        8 + JournalEntryRevision::INIT_SPACE
+            - MAX_TITLE_LENGTH as usize
+            - MAX_MESSAGE_LENGTH as usize
+            + title_len
+            + message_len
+    }
+
+    /// Captures the current content of `entry`, to be called before the entry is updated.
+    pub fn capture(entry: &Account<JournalEntryState>, snapshotted_at: i64) -> Self {
+        Self {
+            entry: entry.key(),
+            owner: entry.owner,
+            index: entry.edit_count,
+            title: entry.title.clone(),
+            message: entry.message.clone(),
+            snapshotted_at,
+        }
+    }
+}
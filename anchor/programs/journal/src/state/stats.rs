@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+
+/// A shard of the program-wide entry statistics, which clients can show without an indexer by
+/// summing the counters of the `GLOBAL_STATS_SHARDS` shards. Each entry is counted in the shard
+/// of its author, so that writers do not all contend for one account, and the shard is created
+/// with the first entry it counts.
+#[account]
+#[derive(InitSpace)]
+pub struct GlobalStats {
+    /// The number of entries ever created.
+    pub total_entries: u64,
+    /// The number of entries that exist and are not in the trash.
+    pub active_entries: u64,
+    /// The number of users who have created at least one entry under their profile.
+    pub unique_writers: u64,
+    /// The bump of the account's address.
+    pub bump: u8,
+}
+
+impl GlobalStats {
+    /// Returns the number of the shard counting the entries of `author`.
+    pub fn shard_of(author: &Pubkey) -> u8 {
+        (author.to_bytes()[0] as u32 % GLOBAL_STATS_SHARDS) as u8
+    }
+
+    /// Counts a newly created entry, and its writer if it is their first entry, recording the
+    /// shard's bump on first use.
+    pub fn record_entry_created(&mut self, bump: u8, first_entry: bool) {
+        self.bump = bump;
+        self.total_entries += 1;
+        self.active_entries += 1;
+        if first_entry {
+            self.unique_writers += 1;
+        }
+    }
+
+    /// Stops counting an entry that was moved to the trash or closed as active.
+    pub fn record_entry_removed(&mut self) {
+        self.active_entries = self.active_entries.saturating_sub(1);
+    }
+
+    /// Counts an entry restored from the trash as active again.
+    pub fn record_entry_restored(&mut self) {
+        self.active_entries += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn authors_are_spread_over_every_shard() {
+        let mut counts = [0; GLOBAL_STATS_SHARDS as usize];
+        for byte in 0..=u8::MAX {
+            counts[GlobalStats::shard_of(&Pubkey::new_from_array([byte; 32])) as usize] += 1;
+        }
+        assert!(counts
+            .iter()
+            .all(|count| *count == 256 / GLOBAL_STATS_SHARDS));
+    }
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::state::Journal;
+
+/// A single item in a table of contents.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum TocItem {
+    /// A section header. Maximum length is 32 characters.
+    Section {
+        #[max_len(MAX_SECTION_HEADER_LENGTH)]
+        header: String,
+    },
+    /// A reference to a journal entry account.
+    Entry { entry: Pubkey },
+}
+
+/// Represents the author-curated order of a journal's entries.
+#[account]
+#[derive(InitSpace)]
+pub struct TableOfContents {
+    /// The public key of the owner of the journal.
+    pub owner: Pubkey,
+    /// The named journal the table orders, or `None` for the owner's default journal.
+    pub journal: Option<Pubkey>,
+    /// The ordered items of the table of contents. Maximum of 64 items.
+    #[max_len(MAX_TOC_ITEMS)]
+    pub items: Vec<TocItem>,
+}
+
+impl TableOfContents {
+    /// Returns the key a table of contents is derived from: its named journal, or the owner for
+    /// their default journal.
+    pub fn scope(owner: Pubkey, journal: Option<&Account<Journal>>) -> Pubkey {
+        journal.map_or(owner, |journal| journal.key())
+    }
+}
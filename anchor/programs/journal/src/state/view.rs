@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+/// Records that a wallet has viewed a journal entry.
+/// One account exists per (viewer, entry) pair, which makes each wallet count once.
+#[account]
+#[derive(InitSpace)]
+pub struct EntryView {
+    /// The public key of the wallet that viewed the entry.
+    pub viewer: Pubkey,
+    /// The journal entry that was viewed.
+    pub entry: Pubkey,
+    /// The Unix timestamp of the first view.
+    pub viewed_at: i64,
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::JournalError;
+
+/// The coldest accepted temperature, in tenths of a degree Celsius.
+const MIN_TEMPERATURE: i16 = -1000;
+/// The hottest accepted temperature, in tenths of a degree Celsius.
+const MAX_TEMPERATURE: i16 = 700;
+
+/// The sky conditions when an entry was written.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum WeatherCondition {
+    /// Clear skies.
+    Clear,
+    /// Overcast or partly cloudy.
+    Cloudy,
+    /// Rain or drizzle.
+    Rain,
+    /// Snow or sleet.
+    Snow,
+    /// Thunderstorms.
+    Storm,
+    /// Fog or mist.
+    Fog,
+}
+
+/// A client-supplied weather snapshot attached to an entry at creation.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct WeatherData {
+    /// The temperature, in tenths of a degree Celsius.
+    pub temperature: i16,
+    /// The sky conditions.
+    pub condition: WeatherCondition,
+}
+
+impl WeatherData {
+    /// Ensures the temperature lies within the range observed on Earth, with some margin.
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            (MIN_TEMPERATURE..=MAX_TEMPERATURE).contains(&self.temperature),
+            JournalError::InvalidWeather
+        );
+        Ok(())
+    }
+}
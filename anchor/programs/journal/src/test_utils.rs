@@ -0,0 +1,299 @@
+//! Helpers for unit tests that validate accounts and run handlers off-chain.
+
+use std::cell::Cell;
+use std::collections::BTreeSet;
+use std::sync::Once;
+
+use anchor_lang::solana_program::entrypoint::SUCCESS;
+use anchor_lang::solana_program::program_stubs::{self, SyscallStubs};
+use anchor_lang::solana_program::sysvar::{self, instructions::BorrowedInstruction};
+use anchor_lang::{Bumps, Discriminator};
+
+use crate::constants::*;
+use crate::state::*;
+use crate::*;
+
+thread_local! {
+    static NOW: Cell<i64> = const { Cell::new(0) };
+    static STACK_HEIGHT: Cell<u64> = const { Cell::new(1) };
+}
+
+/// Answers the clock and stack height syscalls from per-thread values, so that tests running in
+/// parallel can each pick their own, and the rent syscall with the default rent.
+struct Stubs;
+
+impl SyscallStubs for Stubs {
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = Clock {
+            unix_timestamp: NOW.get(),
+            ..Clock::default()
+        };
+        unsafe { *(var_addr as *mut Clock) = clock };
+        SUCCESS
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { *(var_addr as *mut Rent) = Rent::default() };
+        SUCCESS
+    }
+
+    fn sol_get_stack_height(&self) -> u64 {
+        STACK_HEIGHT.get()
+    }
+}
+
+fn install_stubs() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        program_stubs::set_syscall_stubs(Box::new(Stubs));
+    });
+}
+
+/// Sets the Unix timestamp returned by `Clock::get` on the current thread.
+pub fn set_now(now: i64) {
+    install_stubs();
+    NOW.set(now);
+}
+
+/// Sets the stack height seen on the current thread: 1 for a top-level instruction, more for a
+/// CPI.
+pub fn set_stack_height(stack_height: u64) {
+    install_stubs();
+    STACK_HEIGHT.set(stack_height);
+}
+
+/// Returns a program configuration with the default limits and no CPI restrictions.
+pub fn config() -> ProgramConfig {
+    ProgramConfig {
+        authority: Pubkey::new_unique(),
+        reject_cpi: false,
+        cpi_allowlist: Vec::new(),
+        treasury: Pubkey::new_unique(),
+        treasury_fee_bps: 0,
+        slug_claim_fee: 0,
+        max_title_len: MAX_TITLE_LENGTH,
+        max_message_len: MAX_MESSAGE_LENGTH,
+        trash_grace_period: DEFAULT_TRASH_GRACE_PERIOD,
+    }
+}
+
+/// An account owned by the test, from which an `AccountInfo` can be borrowed.
+pub struct TestAccount {
+    pub key: Pubkey,
+    pub owner: Pubkey,
+    pub lamports: u64,
+    /// The account data, preceded by its length as the runtime serializes it, so that
+    /// `AccountInfo::realloc` can shrink or close the account in place. Accounts cannot grow.
+    buffer: Vec<u8>,
+    pub is_signer: bool,
+    pub is_writable: bool,
+    pub executable: bool,
+}
+
+impl TestAccount {
+    fn buffer(data: &[u8]) -> Vec<u8> {
+        [&(data.len() as u64).to_le_bytes(), data].concat()
+    }
+
+    /// A system-owned wallet signing the transaction.
+    pub fn signer(key: Pubkey) -> Self {
+        Self {
+            key,
+            owner: System::id(),
+            lamports: 1_000_000_000,
+            buffer: Self::buffer(&[]),
+            is_signer: true,
+            is_writable: true,
+            executable: false,
+        }
+    }
+
+    /// A writable program account holding `account`.
+    pub fn program<T: AccountSerialize>(key: Pubkey, account: &T) -> Self {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        Self {
+            key,
+            owner: crate::ID,
+            lamports: Rent::default().minimum_balance(data.len()),
+            buffer: Self::buffer(&data),
+            is_signer: false,
+            is_writable: true,
+            executable: false,
+        }
+    }
+
+    /// A writable program account holding `account`, allocated at `space` bytes like `init`
+    /// allocates it, so that it can be written back after its optional fields are set.
+    pub fn allocated<T: AccountSerialize>(key: Pubkey, account: &T, space: usize) -> Self {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        data.resize(space, 0);
+        Self {
+            lamports: Rent::default().minimum_balance(space),
+            buffer: Self::buffer(&data),
+            ..Self::program(key, account)
+        }
+    }
+
+    /// A journal entry, allocated at its full size like the entry instructions allocate it.
+    pub fn entry(key: Pubkey, entry: &JournalEntryState) -> Self {
+        let space = JournalEntryState::space(entry.title.len(), entry.body_len());
+        Self::allocated(key, entry, space)
+    }
+
+    /// The program configuration, at its address.
+    pub fn config(config: &ProgramConfig) -> Self {
+        let (key, _) = Pubkey::find_program_address(&[CONFIG_SEED.as_ref()], &crate::ID);
+        Self::program(key, config).read_only()
+    }
+
+    /// The instructions sysvar of a transaction whose current, top-level instruction invokes
+    /// `program_id`.
+    pub fn instructions(program_id: &Pubkey) -> Self {
+        let instruction = BorrowedInstruction {
+            program_id,
+            accounts: Vec::new(),
+            data: &[],
+        };
+        Self {
+            key: sysvar::instructions::ID,
+            owner: sysvar::ID,
+            lamports: 0,
+            buffer: Self::buffer(&sysvar::instructions::construct_instructions_data(&[
+                instruction,
+            ])),
+            is_signer: false,
+            is_writable: false,
+            executable: false,
+        }
+    }
+
+    /// The program at `program_id`, such as the system program.
+    pub fn executable(program_id: Pubkey) -> Self {
+        Self {
+            key: program_id,
+            executable: true,
+            ..Self::absent()
+        }
+    }
+
+    /// The placeholder passed for an optional account that is omitted.
+    pub fn absent() -> Self {
+        Self {
+            key: crate::ID,
+            owner: Pubkey::default(),
+            lamports: 0,
+            buffer: Self::buffer(&[]),
+            is_signer: false,
+            is_writable: false,
+            executable: false,
+        }
+    }
+
+    /// Returns the account data, as left by the instruction.
+    pub fn data(&self) -> &[u8] {
+        let len = u64::from_le_bytes(self.buffer[..8].try_into().unwrap());
+        &self.buffer[8..8 + len as usize]
+    }
+
+    /// Deserializes the account data as a `T`.
+    pub fn state<T: AccountDeserialize>(&self) -> T {
+        T::try_deserialize(&mut self.data()).unwrap()
+    }
+
+    /// Marks the account read-only.
+    pub fn read_only(mut self) -> Self {
+        self.is_writable = false;
+        self
+    }
+}
+
+/// Borrows `AccountInfo`s for `accounts`, in order.
+pub fn infos(accounts: &mut [TestAccount]) -> Vec<AccountInfo<'_>> {
+    install_stubs();
+    accounts
+        .iter_mut()
+        .map(|account| {
+            let (_, data) = account.buffer.split_at_mut(8);
+            AccountInfo::new(
+                &account.key,
+                account.is_signer,
+                account.is_writable,
+                &mut account.lamports,
+                data,
+                &account.owner,
+                account.executable,
+                0,
+            )
+        })
+        .collect()
+}
+
+/// Validates `infos` as the accounts struct `T`, as the program entrypoint would.
+pub fn try_accounts<'info, T>(
+    infos: &'info [AccountInfo<'info>],
+    ix_data: &[u8],
+) -> Result<(T, T::Bumps)>
+where
+    T: Accounts<'info, T::Bumps> + Bumps,
+    T::Bumps: Default,
+{
+    let mut bumps = T::Bumps::default();
+    let accounts = T::try_accounts(
+        &crate::ID,
+        &mut &infos[..],
+        ix_data,
+        &mut bumps,
+        &mut BTreeSet::new(),
+    )?;
+    Ok((accounts, bumps))
+}
+
+/// Returns a journal entry by `owner` with every other field zeroed, as `init` would leave it,
+/// together with its address.
+pub fn entry(owner: Pubkey, index: u64) -> (Pubkey, JournalEntryState) {
+    let (key, bump) = Pubkey::find_program_address(
+        &[ENTRY_SEED.as_ref(), owner.as_ref(), &index.to_le_bytes()],
+        &crate::ID,
+    );
+    let mut zeroed = vec![0; JournalEntryState::space(0, 0)];
+    zeroed[..8].copy_from_slice(&JournalEntryState::DISCRIMINATOR);
+    let mut entry = JournalEntryState::try_deserialize(&mut zeroed.as_slice()).unwrap();
+    entry.owner = owner;
+    entry.author = owner;
+    entry.index = index;
+    entry.bump = bump;
+    (key, entry)
+}
+
+/// Returns the profile of `owner` with every other field zeroed, together with its address.
+pub fn profile(owner: Pubkey) -> (Pubkey, UserProfile) {
+    let (key, bump) =
+        Pubkey::find_program_address(&[PROFILE_SEED.as_ref(), owner.as_ref()], &crate::ID);
+    let mut zeroed = vec![0; 8 + UserProfile::INIT_SPACE];
+    zeroed[..8].copy_from_slice(&UserProfile::DISCRIMINATOR);
+    let mut profile = UserProfile::try_deserialize(&mut zeroed.as_slice()).unwrap();
+    profile.owner = owner;
+    profile.bump = bump;
+    (key, profile)
+}
+
+/// Returns the empty entry index page `page` of `owner`, together with its address.
+pub fn index_page(owner: Pubkey, page: u64) -> (Pubkey, EntryIndexPage) {
+    let (key, bump) = Pubkey::find_program_address(
+        &[
+            ENTRY_INDEX_SEED.as_ref(),
+            owner.as_ref(),
+            &page.to_le_bytes(),
+        ],
+        &crate::ID,
+    );
+    let index_page = EntryIndexPage {
+        owner,
+        page,
+        bump,
+        entries: Vec::new(),
+    };
+    (key, index_page)
+}
@@ -1,4 +1,5 @@
 // Here we export some useful types and functions for interacting with the Anchor program.
+import { BN } from '@coral-xyz/anchor';
 import { Cluster, PublicKey } from '@solana/web3.js';
 import type { Journal } from '../target/types/journal';
 import { IDL as JournalIDL } from '../target/types/journal';
@@ -20,4 +21,114 @@ export function getJournalProgramId(cluster: Cluster) {
     default:
       return JOURNAL_PROGRAM_ID;
   }
-}
\ No newline at end of file
+}
+// Reads a numeric limit exported by the program with `#[constant]`.
+function getJournalLimit(name: string): number {
+  const constant = JournalIDL.constants.find((c) => c.name === name);
+  if (!constant) {
+    throw new Error(`Missing IDL constant ${name}`);
+  }
+  return Number(constant.value);
+}
+
+export const MAX_TITLE_LENGTH = getJournalLimit('MAX_TITLE_LENGTH');
+export const MAX_MESSAGE_LENGTH = getJournalLimit('MAX_MESSAGE_LENGTH');
+
+// The offset of an entry's category byte, for `memcmp` filters on `getProgramAccounts`.
+// The category is stored as its variant index: Personal = 0, Work = 1, Travel = 2,
+// Health = 3, Other = 4.
+export const ENTRY_CATEGORY_OFFSET = getJournalLimit('ENTRY_CATEGORY_OFFSET');
+
+// Mirrors the program's title and message checks, which count UTF-8 bytes rather than
+// characters. Returns a readable reason, or null if the entry is valid.
+export function validateJournalEntry(title: string, message: string): string | null {
+  const encoder = new TextEncoder();
+  if (title.trim() === '') {
+    return 'Title must not be empty';
+  }
+  if (encoder.encode(title).length > MAX_TITLE_LENGTH) {
+    return `Title must be at most ${MAX_TITLE_LENGTH} bytes`;
+  }
+  if (encoder.encode(message).length > MAX_MESSAGE_LENGTH) {
+    return `Message must be at most ${MAX_MESSAGE_LENGTH} bytes`;
+  }
+  return null;
+}
+
+// Derives the address of a user's profile, which counts the entries they have created.
+export function getUserProfileAddress(
+  owner: PublicKey,
+  programId: PublicKey = JOURNAL_PROGRAM_ID
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('profile'), owner.toBuffer()],
+    programId
+  )[0];
+}
+
+// Derives a journal entry's address from its author, the wallet that created it, and its
+// index in the author's journal. The address does not change if the entry changes owner.
+export function getJournalEntryAddress(
+  author: PublicKey,
+  index: BN,
+  programId: PublicKey = JOURNAL_PROGRAM_ID
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('entry'), author.toBuffer(), index.toArrayLike(Buffer, 'le', 8)],
+    programId
+  )[0];
+}
+
+export const ENTRY_INDEX_PAGE_CAPACITY = getJournalLimit('ENTRY_INDEX_PAGE_CAPACITY');
+
+// Derives the address of the entry index page listing the author's entry at `index`. Reading
+// pages from page 0 until one is missing enumerates the author's published entries; drafts are
+// listed once they are published.
+export function getEntryIndexPageAddress(
+  author: PublicKey,
+  index: BN,
+  programId: PublicKey = JOURNAL_PROGRAM_ID
+): PublicKey {
+  const page = index.divn(ENTRY_INDEX_PAGE_CAPACITY);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('entry_index'), author.toBuffer(), page.toArrayLike(Buffer, 'le', 8)],
+    programId
+  )[0];
+}
+
+export const GLOBAL_STATS_SHARDS = getJournalLimit('GLOBAL_STATS_SHARDS');
+
+// Derives the address of the global statistics shard counting the author's entries. The
+// program-wide statistics are the sums over shards 0 to GLOBAL_STATS_SHARDS - 1.
+export function getGlobalStatsAddress(
+  author: PublicKey,
+  programId: PublicKey = JOURNAL_PROGRAM_ID
+): PublicKey {
+  const shard = author.toBuffer()[0] % GLOBAL_STATS_SHARDS;
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('stats'), Buffer.from([shard])],
+    programId
+  )[0];
+}
+
+// Derives the address of an entry's audit log, which is closed along with the entry.
+export function getAuditLogAddress(
+  entry: PublicKey,
+  programId: PublicKey = JOURNAL_PROGRAM_ID
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('audit'), entry.toBuffer()],
+    programId
+  )[0];
+}
+
+// Derives the address of an entry's revision history, which is closed along with the entry.
+export function getRevisionHistoryAddress(
+  entry: PublicKey,
+  programId: PublicKey = JOURNAL_PROGRAM_ID
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('revision'), entry.toBuffer()],
+    programId
+  )[0];
+}
@@ -1,19 +1,32 @@
 'use client';
 
-import { JournalIDL } from '@my-journal-dapp/anchor';
-import { Program } from '@coral-xyz/anchor';
+import {
+  JournalIDL,
+  getAuditLogAddress,
+  getEntryIndexPageAddress,
+  getGlobalStatsAddress,
+  getJournalEntryAddress,
+  getRevisionHistoryAddress,
+  getUserProfileAddress,
+} from '@my-journal-dapp/anchor';
+import { BN, Program } from '@coral-xyz/anchor';
 import { useConnection } from '@solana/wallet-adapter-react';
-import { PublicKey } from '@solana/web3.js';
+import { PublicKey, SYSVAR_INSTRUCTIONS_PUBKEY } from '@solana/web3.js';
 import { useMutation, useQuery } from '@tanstack/react-query';
 import toast from 'react-hot-toast';
 import { useCluster } from '../cluster/cluster-data-access';
 import { useAnchorProvider } from '../solana/solana-provider';
 import { useTransactionToast } from '../ui/ui-layout';
 
+// Every mutating instruction takes the program configuration and the instructions sysvar to
+// enforce the program's CPI policy. The configuration is resolved from its seeds.
+const cpiGuard = { instructions: SYSVAR_INSTRUCTIONS_PUBKEY };
+
 interface CreateEntryArgs {
   title: string;
   message: string;
   owner: PublicKey;
+  draft?: boolean;
 }
 
 export function useJournalProgram() {
@@ -36,16 +49,34 @@ export function useJournalProgram() {
 
   const createEntry = useMutation<string, Error, CreateEntryArgs>({
     mutationKey: ['journalEntry', 'create', { cluster }],
-    mutationFn: async ({ title, message, owner }) => {
-      const [journalEntryAddress] = await PublicKey.findProgramAddress(
-        [Buffer.from(title), owner.toBuffer()],
-        programId
-      );
-  
+    mutationFn: async ({ title, message, owner, draft = false }) => {
+      // The new entry takes the next index from the owner's profile, which does not exist
+      // before their first entry, and is linked after their latest entry. The config PDA is
+      // resolved from the IDL seeds.
+      const profile = getUserProfileAddress(owner, programId);
+      const existing = await program.account.userProfile.fetchNullable(profile);
+      const index = (existing?.entryCount as BN | undefined) ?? new BN(0);
+
       return program.methods
-        .createJournalEntry(title, message)
+        .createJournalEntry(title, message, {
+          draft,
+          publishAt: null,
+          tags: [],
+          category: { personal: {} },
+          contentType: { plainText: {} },
+          lang: [0, 0],
+          weather: null,
+          prompt: null,
+        })
         .accounts({
-          journalEntry: journalEntryAddress,
+          profile,
+          journalEntry: getJournalEntryAddress(owner, index, programId),
+          tailEntry: existing?.tailEntry ?? null,
+          indexPage: getEntryIndexPageAddress(owner, index, programId),
+          globalStats: getGlobalStatsAddress(owner, programId),
+          owner,
+          payer: owner,
+          cpiGuard,
         })
         .rpc();
     },
@@ -77,34 +108,148 @@ export function useJournalProgramAccount({ account }: { account: PublicKey }) {
     queryFn: () => program.account.journalEntryState.fetch(account),
   });
 
+  // Edits, deletions and restores update the statistics in the profile of the entry's author
+  // and in the author's global statistics shard, and purging unlinks the entry from the
+  // author's entry list and index pages.
+  const authorProfile = () =>
+    getUserProfileAddress(accountQuery.data!.author, programId);
+  const globalStats = () => getGlobalStatsAddress(accountQuery.data!.author, programId);
+  const indexPage = () =>
+    getEntryIndexPageAddress(accountQuery.data!.author, accountQuery.data!.index, programId);
+
   const updateEntry = useMutation<string, Error, CreateEntryArgs>({
     mutationKey: ['journalEntry', 'update', { cluster }],
-    mutationFn: async ({ title, message, owner }) => {
-      const [journalEntryAddress] = await PublicKey.findProgramAddress(
-        [Buffer.from(title), owner.toBuffer()],
-        programId
-      );
-  
-      return program.methods
-        .updateJournalEntry(title, message)
+    mutationFn: async ({ message, owner }) =>
+      // Only the message is edited here, so the title is not resent. The version this view
+      // read is passed along so an edit made elsewhere in the meantime is not overwritten.
+      program.methods
+        .updateMessage(message, accountQuery.data?.version ?? new BN(0))
         .accounts({
-          journalEntry: journalEntryAddress,
+          journalEntry: account,
+          profile: authorProfile(),
+          owner,
+          payer: owner,
+          cpiGuard,
         })
-        .rpc();
-    },
+        .rpc(),
     onSuccess: (signature) => {
       transactionToast(signature);
       accounts.refetch();
     },
     onError: (error) => {
       toast.error(`Failed to update journal entry: ${error.message}`);
+      accountQuery.refetch();
     },
   });
 
   const deleteEntry = useMutation({
     mutationKey: ['journal', 'deleteEntry', { cluster, account }],
-    mutationFn: (title: string) =>
-      program.methods.deleteJournalEntry(title).accounts({ journalEntry: account }).rpc(),
+    mutationFn: () =>
+      program.methods
+        .deleteJournalEntry()
+        .accounts({
+          journalEntry: account,
+          profile: authorProfile(),
+          globalStats: globalStats(),
+          owner: program.provider.publicKey,
+          cpiGuard,
+        })
+        .rpc(),
+    onSuccess: (tx) => {
+      transactionToast(tx);
+      return accounts.refetch();
+    },
+  });
+
+  const restoreEntry = useMutation({
+    mutationKey: ['journal', 'restoreEntry', { cluster, account }],
+    mutationFn: () =>
+      program.methods
+        .restoreEntry()
+        .accounts({
+          journalEntry: account,
+          profile: authorProfile(),
+          globalStats: globalStats(),
+          owner: program.provider.publicKey,
+          cpiGuard,
+        })
+        .rpc(),
+    onSuccess: (tx) => {
+      transactionToast(tx);
+      return accounts.refetch();
+    },
+  });
+
+  const purgeEntry = useMutation({
+    mutationKey: ['journal', 'purgeEntry', { cluster, account }],
+    mutationFn: async () => {
+      // The entry's view records are closed with it, each followed by the viewer who paid for
+      // it. The record's entry follows the 8-byte discriminator and the viewer's key.
+      const views = await program.account.entryView.all([
+        { memcmp: { offset: 40, bytes: account.toBase58() } },
+      ]);
+      return program.methods
+        .purgeEntry()
+        .accounts({
+          journalEntry: account,
+          owner: program.provider.publicKey,
+          rentRecipient: program.provider.publicKey,
+          journal: accountQuery.data?.journal ?? null,
+          profile: authorProfile(),
+          prevEntry: accountQuery.data?.prevEntry ?? null,
+          nextEntry: accountQuery.data?.nextEntry ?? null,
+          indexPage: indexPage(),
+          auditLog: accountQuery.data?.audited ? getAuditLogAddress(account, programId) : null,
+          revisionHistory: accountQuery.data?.revisioned
+            ? getRevisionHistoryAddress(account, programId)
+            : null,
+          cpiGuard,
+        })
+        .remainingAccounts(
+          views.flatMap((view) => [
+            { pubkey: view.publicKey, isSigner: false, isWritable: true },
+            { pubkey: view.account.viewer, isSigner: false, isWritable: true },
+          ])
+        )
+        .rpc();
+    },
+    onSuccess: (tx) => {
+      transactionToast(tx);
+      return accounts.refetch();
+    },
+  });
+
+  const publishEntry = useMutation({
+    mutationKey: ['journal', 'publishEntry', { cluster, account }],
+    mutationFn: () =>
+      program.methods
+        .publishEntry()
+        .accounts({
+          journalEntry: account,
+          owner: program.provider.publicKey,
+          indexPage: indexPage(),
+          cpiGuard,
+        })
+        .rpc(),
+    onSuccess: (tx) => {
+      transactionToast(tx);
+      return accounts.refetch();
+    },
+  });
+
+  const archiveEntry = useMutation({
+    mutationKey: ['journal', 'archiveEntry', { cluster, account }],
+    mutationFn: (archived: boolean) =>
+      (archived
+        ? program.methods.archiveEntry()
+        : program.methods.unarchiveEntry()
+      )
+        .accounts({
+          journalEntry: account,
+          owner: program.provider.publicKey,
+          cpiGuard,
+        })
+        .rpc(),
     onSuccess: (tx) => {
       transactionToast(tx);
       return accounts.refetch();
@@ -114,6 +259,10 @@ export function useJournalProgramAccount({ account }: { account: PublicKey }) {
   return {
     accountQuery,
     updateEntry, 
-    deleteEntry
+    deleteEntry,
+    restoreEntry,
+    purgeEntry,
+    publishEntry,
+    archiveEntry,
   };
 }
\ No newline at end of file
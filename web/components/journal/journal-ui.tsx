@@ -1,5 +1,7 @@
 'use client';
 
+import { BN } from '@coral-xyz/anchor';
+import { validateJournalEntry } from '@my-journal-dapp/anchor';
 import { Keypair, PublicKey } from '@solana/web3.js';
 // import { useMemo } from 'react';
 import { ellipsify } from '../ui/ui-layout';
@@ -16,12 +18,14 @@ export function JournalCreate() {
   const { publicKey } = useWallet();
   const [title, setTitle] = useState('');
   const [message, setMessage] = useState('');
+  const [draft, setDraft] = useState(false);
 
-  const isFormValid = title.trim() !== '' && message.trim() !== '';
+  const validationError = validateJournalEntry(title, message);
+  const isFormValid = !validationError && message.trim() !== '';
 
   const handleSubmit = () => {
     if (publicKey && isFormValid) {
-      createEntry.mutateAsync({ title, message, owner: publicKey });
+      createEntry.mutateAsync({ title, message, owner: publicKey, draft });
     }
   };
 
@@ -44,6 +48,18 @@ export function JournalCreate() {
         onChange={(e) => setMessage(e.target.value)}
         className="textarea textarea-bordered w-full max-w-xs"
       />
+      {title && validationError && (
+        <p className="text-error text-sm">{validationError}</p>
+      )}
+      <label className="label cursor-pointer justify-start gap-2">
+        <input
+          type="checkbox"
+          checked={draft}
+          onChange={(e) => setDraft(e.target.checked)}
+          className="checkbox checkbox-sm"
+        />
+        <span className="label-text">Save as draft</span>
+      </label>
       <br></br>
       <button
         className="btn btn-xs lg:btn-md btn-primary"
@@ -79,7 +95,10 @@ export function JournalList() {
         <span className="loading loading-spinner loading-lg"></span>
       ) : accounts.data?.length ? (
         <div className="grid gap-4 md:grid-cols-2">
-          {accounts.data?.map((account) => (
+          {accounts.data
+            .filter((account) => !account.account.isArchived)
+            .sort((a, b) => b.account.updatedAt.cmp(a.account.updatedAt))
+            .map((account) => (
             <JournalCard
               key={account.publicKey.toString()}
               account={account.publicKey}
@@ -99,19 +118,33 @@ export function JournalList() {
 type JournalEntryState = {
   message: string;
   title: string;
+  encryption: object | null;
+  createdAt: BN;
+  updatedAt: BN;
+  editCount: number;
+  isArchived: boolean;
+  status: { draft?: object; published?: object };
+  deletedAt: BN | null;
 }
 
 function JournalCard({ account }: { account: PublicKey }) {
   const {
     accountQuery,
     updateEntry, 
-    deleteEntry
+    deleteEntry,
+    restoreEntry,
+    purgeEntry,
+    publishEntry,
+    archiveEntry,
   } = useJournalProgramAccount({ account });
   const { publicKey } = useWallet();
   const [message, setMessage] = useState('');
   const title = (accountQuery.data as JournalEntryState)?.title; 
+  const trashed = (accountQuery.data as JournalEntryState)?.deletedAt != null;
+  const isDraft = (accountQuery.data as JournalEntryState)?.status?.draft !== undefined;
 
-  const isFormValid = message.trim() !== '';
+  const validationError = title ? validateJournalEntry(title, message) : null;
+  const isFormValid = !validationError && message.trim() !== '';
 
   const handleSubmit = () => {
     if (publicKey && isFormValid && title) {
@@ -136,8 +169,21 @@ function JournalCard({ account }: { account: PublicKey }) {
             {(accountQuery.data as JournalEntryState)?.title}
           </h2>
           <p> 
-          {(accountQuery.data as JournalEntryState)?.message}
+          {(accountQuery.data as JournalEntryState)?.encryption
+            ? 'Encrypted entry'
+            : (accountQuery.data as JournalEntryState)?.message}
           </p>
+          {(accountQuery.data as JournalEntryState)?.updatedAt && (
+            <p className="text-xs opacity-60">
+              Updated{' '}
+              {new Date(
+                (accountQuery.data as JournalEntryState).updatedAt.toNumber() * 1000
+              ).toLocaleString()}
+              {(accountQuery.data as JournalEntryState).editCount > 0 &&
+                ` · edited ${(accountQuery.data as JournalEntryState).editCount} times`}
+            </p>
+          )}
+          {!trashed && (
           <div className="card-actions justify-around">
             <textarea
               placeholder="Update message here"
@@ -145,6 +191,9 @@ function JournalCard({ account }: { account: PublicKey }) {
               onChange={(e) => setMessage(e.target.value)}
               className="textarea textarea-bordered w-full max-w-xs"
             />
+            {validationError && (
+              <p className="text-error text-sm">{validationError}</p>
+            )}
             <button
               className="btn btn-xs lg:btn-md btn-primary"
               onClick={handleSubmit}
@@ -153,6 +202,7 @@ function JournalCard({ account }: { account: PublicKey }) {
               Update Journal Entry {updateEntry.isPending && '...'}
             </button>
           </div>
+          )}
           <div className="text-center space-y-4">
             <p>
               <ExplorerLink
@@ -160,25 +210,63 @@ function JournalCard({ account }: { account: PublicKey }) {
                 label={ellipsify(account.toString())}
               />
             </p>
-            <button
-              className="btn btn-xs btn-secondary btn-outline"
-              onClick={() => {
-                if (
-                  !window.confirm(
-                    'Are you sure you want to close this account?'
-                  )
-                ) {
-                  return;
-                }
-                const title = (accountQuery.data as JournalEntryState)?.title;
-                if (title) {
-                  return deleteEntry.mutateAsync(title);
-                }
-              }}
-              disabled={deleteEntry.isPending}
-            >
-              Close
-            </button>
+            {trashed ? (
+              <>
+                <p className="text-xs opacity-60">In trash</p>
+                <button
+                  className="btn btn-xs btn-outline"
+                  onClick={() => restoreEntry.mutateAsync()}
+                  disabled={restoreEntry.isPending}
+                >
+                  Restore
+                </button>
+                <button
+                  className="btn btn-xs btn-secondary btn-outline"
+                  onClick={() => {
+                    if (
+                      !window.confirm(
+                        'Are you sure you want to permanently close this account?'
+                      )
+                    ) {
+                      return;
+                    }
+                    return purgeEntry.mutateAsync();
+                  }}
+                  disabled={purgeEntry.isPending}
+                >
+                  Delete permanently
+                </button>
+              </>
+            ) : (
+              <>
+                {isDraft && (
+                  <>
+                    <p className="text-xs opacity-60">Draft</p>
+                    <button
+                      className="btn btn-xs btn-primary btn-outline"
+                      onClick={() => publishEntry.mutateAsync()}
+                      disabled={publishEntry.isPending}
+                    >
+                      Publish
+                    </button>
+                  </>
+                )}
+                <button
+                  className="btn btn-xs btn-outline"
+                  onClick={() => archiveEntry.mutateAsync(true)}
+                  disabled={archiveEntry.isPending}
+                >
+                  Archive
+                </button>
+                <button
+                  className="btn btn-xs btn-secondary btn-outline"
+                  onClick={() => deleteEntry.mutateAsync()}
+                  disabled={deleteEntry.isPending}
+                >
+                  Move to trash
+                </button>
+              </>
+            )}
           </div>
         </div>
       </div>